@@ -63,6 +63,13 @@ struct FMPResponse {
     shares_outstanding: Option<f64>,
     #[serde(rename = "timestamp")]
     timestamp: Option<i64>,
+    /// FMP's `/quote` endpoint doesn't report top-of-book depth on most
+    /// plans, so these are optional and the spread/book-check signal is
+    /// skipped whenever they're absent.
+    #[serde(rename = "bid", default)]
+    bid: Option<f64>,
+    #[serde(rename = "ask", default)]
+    ask: Option<f64>,
 }
 
 #[derive(Debug, Clone)]
@@ -73,6 +80,83 @@ struct PriceResult {
     timestamp: String,
     metadata: Option<String>,
     error_info: Option<String>,
+    session: TradeSession,
+    /// ISO 4217 currency the (possibly already-normalized) `price` is
+    /// denominated in. Sources that don't report a quote currency are
+    /// assumed USD, matching the oracle's historical default.
+    currency: String,
+}
+
+/// Which part of the trading day a quote was taken in. Quotes pulled during
+/// a closed or halted session, or during pre/post-market, can legitimately
+/// diverge from a regular-session quote on another source -- that shouldn't
+/// be flagged as a statistical outlier the way a regular-session divergence
+/// would be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TradeSession {
+    PreMarket,
+    Regular,
+    PostMarket,
+    Closed,
+    Halted,
+}
+
+impl TradeSession {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TradeSession::PreMarket => "pre-market",
+            TradeSession::Regular => "regular",
+            TradeSession::PostMarket => "post-market",
+            TradeSession::Closed => "closed",
+            TradeSession::Halted => "halted",
+        }
+    }
+}
+
+/// Merges the sessions of the sources feeding into a cross-validated result:
+/// if they all agree, that session carries through; if they disagree, the
+/// result is reported as `Closed` since no single session label would be
+/// accurate for the blend.
+fn combined_session(sessions: &[TradeSession]) -> TradeSession {
+    match sessions.split_first() {
+        Some((first, rest)) if rest.iter().all(|s| s == first) => *first,
+        _ => TradeSession::Closed,
+    }
+}
+
+/// Resolves a `TwelveDataResponse` quote into a `TradeSession`. TwelveData
+/// gives us `is_market_open` plus a quote timestamp; we compare the quote
+/// timestamp against typical US equity hours (13:30-20:00 UTC, i.e.
+/// 9:30am-4:00pm ET, ignoring DST) to distinguish pre- from post-market when
+/// the market is reported closed.
+fn resolve_twelve_data_session(is_market_open: bool, quote_timestamp: i64) -> TradeSession {
+    if is_market_open {
+        return TradeSession::Regular;
+    }
+
+    let seconds_since_midnight_utc = quote_timestamp.rem_euclid(86_400);
+    const MARKET_OPEN_UTC: i64 = 13 * 3600 + 30 * 60; // 13:30 UTC
+    const MARKET_CLOSE_UTC: i64 = 20 * 3600; // 20:00 UTC
+
+    if seconds_since_midnight_utc < MARKET_OPEN_UTC {
+        TradeSession::PreMarket
+    } else if seconds_since_midnight_utc >= MARKET_CLOSE_UTC {
+        TradeSession::PostMarket
+    } else {
+        // Market hours by the clock but reported closed -- a halt.
+        TradeSession::Halted
+    }
+}
+
+/// Thin liquidity outside regular hours makes quotes less reliable, so
+/// sources that don't already bake a session discount into their base
+/// confidence (TwelveData does) get one applied here.
+fn adjust_confidence_for_session(confidence: u8, session: TradeSession) -> u8 {
+    match session {
+        TradeSession::Regular => confidence,
+        TradeSession::PreMarket | TradeSession::PostMarket => confidence.saturating_sub(6),
+        TradeSession::Closed | TradeSession::Halted => confidence.saturating_sub(15),
+    }
 }
 
 #[derive(Debug)]
@@ -83,6 +167,16 @@ struct ValidationResult {
     normalization_applied: bool,
     fuzzy_match: Option<String>,
     warnings: Vec<String>,
+    /// Currency every `PriceResult` should be normalized to before
+    /// cross-validation. Defaults to USD; overridable via a `SYMBOL/CCY`
+    /// suffix on the raw input (e.g. "AAPL/EUR").
+    target_currency: String,
+    /// Candlestick period used for the SMA/recent-range cross-check against
+    /// the spot quote. Defaults to `OneDay`; overridable via a
+    /// `SYMBOL@PERIOD` suffix on the raw input (e.g. "AAPL@1h"). Currently
+    /// only consulted by the FMP candle cross-check -- Alpha Vantage and
+    /// TwelveData don't have one yet.
+    candlestick_period: CandlestickPeriod,
 }
 
 #[derive(Debug, Clone)]
@@ -100,11 +194,82 @@ struct ErrorInfo {
     retry_after: Option<u32>, // seconds to wait before retry
 }
 
+/// Structured error captured at the fetch boundary, before anything gets
+/// collapsed into a human-readable `anyhow::Error`. Carries everything
+/// `classify_error` needs to make a real retry/no-retry decision instead of
+/// grepping a formatted message for substrings.
+#[derive(Debug, Clone)]
+struct FetchError {
+    source: DataSource,
+    /// HTTP status code, or 0 when the failure happened before a status was
+    /// available (transport error, JSON decode failure, etc.).
+    status: u16,
+    /// Raw response body, kept so provider-specific payload errors (e.g.
+    /// Alpha Vantage's HTTP-200 rate-limit `Note`) can be detected precisely.
+    body: String,
+    /// `Retry-After` header value in seconds, when the provider sent one.
+    retry_after_header: Option<u32>,
+    kind: FetchErrorKind,
+}
+
+#[derive(Debug, Clone)]
+enum FetchErrorKind {
+    /// The HTTP layer itself failed (non-2xx status, connection error).
+    Transport,
+    /// The HTTP call succeeded but the body couldn't be decoded into the
+    /// expected response shape.
+    Decode(String),
+    /// The response decoded fine but the payload itself reports failure
+    /// (e.g. Alpha Vantage's `{"Note": "...call frequency..."}`).
+    ApiError(String),
+}
+
+impl std::fmt::Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            FetchErrorKind::Transport => write!(f, "{:?} HTTP error: status {}", self.source, self.status),
+            FetchErrorKind::Decode(msg) => write!(f, "{:?} decode error: {}", self.source, msg),
+            FetchErrorKind::ApiError(msg) => write!(f, "{:?} API error: {}", self.source, msg),
+        }
+    }
+}
+
+impl FetchError {
+    fn transport(source: DataSource, status: u16, body: &[u8], retry_after_header: Option<u32>) -> Self {
+        FetchError {
+            source,
+            status,
+            body: String::from_utf8_lossy(body).into_owned(),
+            retry_after_header,
+            kind: FetchErrorKind::Transport,
+        }
+    }
+
+    fn decode(source: DataSource, body: &[u8], msg: String) -> Self {
+        FetchError {
+            source,
+            status: 0,
+            body: String::from_utf8_lossy(body).into_owned(),
+            retry_after_header: None,
+            kind: FetchErrorKind::Decode(msg),
+        }
+    }
+
+    fn api(source: DataSource, status: u16, body: &[u8], msg: String) -> Self {
+        FetchError {
+            source,
+            status,
+            body: String::from_utf8_lossy(body).into_owned(),
+            retry_after_header: None,
+            kind: FetchErrorKind::ApiError(msg),
+        }
+    }
+}
+
 struct RetryConfig {
     max_attempts: u32,
     base_delay_ms: u32,
     max_delay_ms: u32,
-    exponential_backoff: bool,
 }
 
 impl Default for RetryConfig {
@@ -113,7 +278,6 @@ impl Default for RetryConfig {
             max_attempts: 3,
             base_delay_ms: 1000,  // 1 second
             max_delay_ms: 8000,   // 8 seconds max
-            exponential_backoff: true,
         }
     }
 }
@@ -137,6 +301,13 @@ struct TwelveDataResponse {
     percent_change: String,
     average_volume: String,
     is_market_open: bool,
+    /// Top-of-book bid/ask, when the plan/instrument reports them. Equity
+    /// quotes on lower API tiers frequently omit these, so they're optional
+    /// and the depth/spread signal is simply skipped when absent.
+    #[serde(default)]
+    bid: Option<String>,
+    #[serde(default)]
+    ask: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -146,6 +317,66 @@ enum DataSource {
     TwelveData,
 }
 
+/// Bar period for the OHLC/candlestick retrieval subsystem.
+#[derive(Debug, Clone, Copy)]
+enum CandlestickPeriod {
+    OneMinute,
+    FiveMinute,
+    OneHour,
+    OneDay,
+}
+
+impl CandlestickPeriod {
+    /// FMP's historical-chart endpoint segment for this period.
+    fn fmp_path_segment(&self) -> &'static str {
+        match self {
+            CandlestickPeriod::OneMinute => "1min",
+            CandlestickPeriod::FiveMinute => "5min",
+            CandlestickPeriod::OneHour => "1hour",
+            CandlestickPeriod::OneDay => "1day",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Candle {
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: f64,
+    timestamp: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct FMPIntradayBar {
+    date: String,
+    open: f64,
+    low: f64,
+    high: f64,
+    close: f64,
+    volume: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct FMPDailyBar {
+    date: String,
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    #[serde(default)]
+    #[serde(rename = "adjClose")]
+    adj_close: Option<f64>,
+    volume: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct FMPDailyHistoryResponse {
+    symbol: String,
+    historical: Vec<FMPDailyBar>,
+}
+
 /**
  * Ultra-robust execution phase that NEVER fails completely.
  * Features: Retry logic, graceful degradation, circuit breakers, 
@@ -173,9 +404,11 @@ pub fn execution_phase() -> Result<()> {
     }
 
     let symbol = validation_result.validated_symbol.clone();
+    log!("   • Target currency: {}", validation_result.target_currency);
+    log!("   • Candlestick period: {}", validation_result.candlestick_period.fmp_path_segment());
 
     // Phase 2: Attempt data retrieval with intelligent retry and fallback
-    let final_price = match execute_data_retrieval_with_recovery(&symbol) {
+    let final_price = match execute_data_retrieval_with_recovery(&symbol, &validation_result.target_currency, validation_result.candlestick_period) {
         Some(price) => price,
         None => {
             log!("🆘 All data sources exhausted, executing emergency protocols");
@@ -210,30 +443,30 @@ fn validate_and_normalize_symbol_robust() -> Result<ValidationResult, String> {
     validate_and_normalize_symbol(&dr_inputs_raw)
 }
 
-fn execute_data_retrieval_with_recovery(symbol: &str) -> Option<PriceResult> {
+fn execute_data_retrieval_with_recovery(symbol: &str, target_currency: &str, period: CandlestickPeriod) -> Option<PriceResult> {
     let retry_config = RetryConfig::default();
-    
+
     // Strategy 1: Try all three sources in parallel with retries
     log!("📡 Strategy 1: Parallel data retrieval with retries");
-    if let Some(price) = try_parallel_retrieval_with_retries(symbol, &retry_config) {
+    if let Some(price) = try_parallel_retrieval_with_retries(symbol, target_currency, &retry_config, period) {
         return Some(price);
     }
 
     // Strategy 2: Sequential fallback with extended retries
     log!("📡 Strategy 2: Sequential fallback with extended retries");
-    if let Some(price) = try_sequential_retrieval_with_retries(symbol, &retry_config) {
+    if let Some(price) = try_sequential_retrieval_with_retries(symbol, target_currency, &retry_config, period) {
         return Some(price);
     }
 
     // Strategy 3: Emergency single-source attempts with relaxed validation
     log!("📡 Strategy 3: Emergency single-source with relaxed validation");
-    if let Some(price) = try_emergency_retrieval(symbol) {
+    if let Some(price) = try_emergency_retrieval(symbol, target_currency) {
         return Some(price);
     }
 
     // Strategy 4: Last resort - try alternative symbols
     log!("📡 Strategy 4: Alternative symbol attempts");
-    if let Some(price) = try_alternative_symbols(symbol, &retry_config) {
+    if let Some(price) = try_alternative_symbols(symbol, target_currency, &retry_config, period) {
         return Some(price);
     }
 
@@ -241,13 +474,17 @@ fn execute_data_retrieval_with_recovery(symbol: &str) -> Option<PriceResult> {
     None
 }
 
-fn try_parallel_retrieval_with_retries(symbol: &str, config: &RetryConfig) -> Option<PriceResult> {
+fn try_parallel_retrieval_with_retries(symbol: &str, target_currency: &str, config: &RetryConfig, period: CandlestickPeriod) -> Option<PriceResult> {
     log!("🔄 Attempting parallel retrieval for: {}", symbol);
-    
-    // Try all three sources with retries
-    let av_result = fetch_with_intelligent_retry(symbol, DataSource::AlphaVantage, config);
-    let fmp_result = fetch_with_intelligent_retry(symbol, DataSource::FinancialModelingPrep, config);
-    let td_result = fetch_with_intelligent_retry(symbol, DataSource::TwelveData, config);
+
+    // Try all three sources with retries, normalizing each to the target
+    // currency before any cross-source comparison sees them.
+    let av_result = fetch_with_intelligent_retry(symbol, DataSource::AlphaVantage, config, period)
+        .map(|p| normalize_to_target_currency(p, target_currency, config));
+    let fmp_result = fetch_with_intelligent_retry(symbol, DataSource::FinancialModelingPrep, config, period)
+        .map(|p| normalize_to_target_currency(p, target_currency, config));
+    let td_result = fetch_with_intelligent_retry(symbol, DataSource::TwelveData, config, period)
+        .map(|p| normalize_to_target_currency(p, target_currency, config));
 
     match (av_result, fmp_result, td_result) {
         (Ok(av_price), Ok(fmp_price), Ok(td_price)) => {
@@ -285,32 +522,34 @@ fn try_parallel_retrieval_with_retries(symbol: &str, config: &RetryConfig) -> Op
     }
 }
 
-fn try_sequential_retrieval_with_retries(symbol: &str, config: &RetryConfig) -> Option<PriceResult> {
+fn try_sequential_retrieval_with_retries(symbol: &str, target_currency: &str, config: &RetryConfig, period: CandlestickPeriod) -> Option<PriceResult> {
     log!("🔄 Attempting sequential retrieval with extended retries");
-    
+
     // Extended retry config for sequential attempts
     let extended_config = RetryConfig {
         max_attempts: config.max_attempts + 2,
         base_delay_ms: config.base_delay_ms * 2,
         max_delay_ms: config.max_delay_ms,
-        exponential_backoff: config.exponential_backoff,
     };
 
     // Try TwelveData first (as third source fallback)
-    if let Ok(price) = fetch_with_intelligent_retry(symbol, DataSource::TwelveData, &extended_config) {
+    if let Ok(price) = fetch_with_intelligent_retry(symbol, DataSource::TwelveData, &extended_config, period) {
         log!("✅ TwelveData succeeded on extended retry");
+        let price = normalize_to_target_currency(price, target_currency, &extended_config);
         return Some(enhance_single_source_result(price, "Alpha Vantage & FMP skipped".to_string()));
     }
 
     // Try FMP second (usually more reliable)
-    if let Ok(price) = fetch_with_intelligent_retry(symbol, DataSource::FinancialModelingPrep, &extended_config) {
+    if let Ok(price) = fetch_with_intelligent_retry(symbol, DataSource::FinancialModelingPrep, &extended_config, period) {
         log!("✅ FMP succeeded on extended retry");
+        let price = normalize_to_target_currency(price, target_currency, &extended_config);
         return Some(enhance_single_source_result(price, "Alpha Vantage & TwelveData unavailable".to_string()));
     }
 
     // Try Alpha Vantage with extended retry
-    if let Ok(price) = fetch_with_intelligent_retry(symbol, DataSource::AlphaVantage, &extended_config) {
+    if let Ok(price) = fetch_with_intelligent_retry(symbol, DataSource::AlphaVantage, &extended_config, period) {
         log!("✅ Alpha Vantage succeeded on extended retry");
+        let price = normalize_to_target_currency(price, target_currency, &extended_config);
         return Some(enhance_single_source_result(price, "FMP & TwelveData unavailable".to_string()));
     }
 
@@ -318,75 +557,79 @@ fn try_sequential_retrieval_with_retries(symbol: &str, config: &RetryConfig) ->
     None
 }
 
-fn try_emergency_retrieval(symbol: &str) -> Option<PriceResult> {
-    log!("🆘 Emergency retrieval mode - relaxed validation");
-    
+/// Doesn't take a candlestick period: this goes through `QuotesProvider`'s
+/// relaxed `fetch`, which always uses the FMP candle cross-check's default
+/// `OneDay` period -- not worth threading through this already-relaxed,
+/// single-attempt emergency path.
+fn try_emergency_retrieval(symbol: &str, target_currency: &str) -> Option<PriceResult> {
+    log!("🆘 Emergency retrieval mode - relaxed validation via Quotes consensus");
+
     // Single attempt with relaxed validation
-    let _relaxed_config = RetryConfig {
+    let relaxed_config = RetryConfig {
         max_attempts: 1,
         base_delay_ms: 500,
         max_delay_ms: 500,
-        exponential_backoff: false,
     };
 
-    // Try TwelveData with relaxed error handling first in emergency
-    if let Ok(mut price) = fetch_twelve_data_price_relaxed(symbol) {
-        price.confidence = (price.confidence as f64 * 0.7) as u8; // Reduce confidence
-        price.source = format!("{} (Emergency Mode)", price.source);
-        log!("✅ Emergency TwelveData succeeded");
-        return Some(price);
-    }
-
-    // Try with relaxed error handling
-    if let Ok(mut price) = fetch_alpha_vantage_price_relaxed(symbol) {
-        price.confidence = (price.confidence as f64 * 0.7) as u8; // Reduce confidence
-        price.source = format!("{} (Emergency Mode)", price.source);
-        log!("✅ Emergency Alpha Vantage succeeded");
-        return Some(price);
-    }
-
-    if let Ok(mut price) = fetch_fmp_price_relaxed(symbol) {
-        price.confidence = (price.confidence as f64 * 0.7) as u8; // Reduce confidence  
-        price.source = format!("{} (Emergency Mode)", price.source);
-        log!("✅ Emergency FMP succeeded");
-        return Some(price);
+    // Query every relaxed provider and reconcile whatever answers instead of
+    // returning on the first success -- this is real multi-source
+    // reconciliation in place of the old "try TD, then AV, then FMP" chain.
+    let providers: Vec<&dyn QuotesProvider> = vec![&TwelveDataQuotes, &AlphaVantageQuotes, &FmpQuotes];
+    let mut cache = QuoteCache::new(0);
+
+    match consensus_quote(&providers, symbol, &mut cache) {
+        Some(mut price) => {
+            price.confidence = (price.confidence as f64 * 0.7) as u8; // Reduce confidence
+            price.source = format!("{} (Emergency Mode)", price.source);
+            price = normalize_to_target_currency(price, target_currency, &relaxed_config);
+            log!("✅ Emergency consensus succeeded: {}", price.source);
+            Some(price)
+        }
+        None => {
+            log!("❌ Emergency retrieval failed");
+            None
+        }
     }
-
-    log!("❌ Emergency retrieval failed");
-    None
 }
 
-fn try_alternative_symbols(symbol: &str, config: &RetryConfig) -> Option<PriceResult> {
+fn try_alternative_symbols(symbol: &str, target_currency: &str, config: &RetryConfig, period: CandlestickPeriod) -> Option<PriceResult> {
     log!("🔀 Trying alternative symbols for: {}", symbol);
-    
+
     let alternatives = generate_alternative_symbols(symbol);
-    
+
     for alt_symbol in alternatives {
         log!("🔍 Trying alternative: {}", alt_symbol);
-        
+
         // Try TwelveData first for alternatives
-        if let Ok(mut price) = fetch_with_intelligent_retry(&alt_symbol, DataSource::TwelveData, config) {
+        if let Ok(mut price) = fetch_with_intelligent_retry(&alt_symbol, DataSource::TwelveData, config, period) {
             price.confidence = (price.confidence as f64 * 0.8) as u8; // Reduce confidence for alternative
             price.source = format!("{} (Alternative: {}→{})", price.source, symbol, alt_symbol);
+            price = normalize_to_target_currency(price, target_currency, config);
             log!("✅ Alternative symbol {} succeeded with TwelveData", alt_symbol);
             return Some(price);
         }
-        
-        if let Ok(mut price) = fetch_with_intelligent_retry(&alt_symbol, DataSource::FinancialModelingPrep, config) {
+
+        if let Ok(mut price) = fetch_with_intelligent_retry(&alt_symbol, DataSource::FinancialModelingPrep, config, period) {
             price.confidence = (price.confidence as f64 * 0.8) as u8; // Reduce confidence for alternative
             price.source = format!("{} (Alternative: {}→{})", price.source, symbol, alt_symbol);
+            price = normalize_to_target_currency(price, target_currency, config);
             log!("✅ Alternative symbol {} succeeded with FMP", alt_symbol);
             return Some(price);
         }
     }
-    
+
     log!("❌ No alternative symbols worked");
     None
 }
 
-fn fetch_with_intelligent_retry(symbol: &str, source: DataSource, config: &RetryConfig) -> Result<PriceResult, ErrorInfo> {
+fn fetch_with_intelligent_retry(symbol: &str, source: DataSource, config: &RetryConfig, period: CandlestickPeriod) -> Result<PriceResult, ErrorInfo> {
     let mut attempt = 0;
     let mut last_error: Option<ErrorInfo> = None;
+    // Decorrelated jitter state: seeded to base_delay_ms, then grown each
+    // retry as min(max, random_between(base, prev*3)). Keeping this outside
+    // the exponential schedule means every SEDA executor node backs off on a
+    // different delay instead of all retrying in lockstep.
+    let mut prev_delay_ms = config.base_delay_ms;
 
     while attempt < config.max_attempts {
         attempt += 1;
@@ -394,7 +637,7 @@ fn fetch_with_intelligent_retry(symbol: &str, source: DataSource, config: &Retry
 
         let result = match source {
             DataSource::AlphaVantage => fetch_alpha_vantage_price(symbol),
-            DataSource::FinancialModelingPrep => fetch_fmp_price(symbol),
+            DataSource::FinancialModelingPrep => fetch_fmp_price_for_period(symbol, period),
             DataSource::TwelveData => fetch_twelve_data_price(symbol),
         };
 
@@ -408,7 +651,7 @@ fn fetch_with_intelligent_retry(symbol: &str, source: DataSource, config: &Retry
             Err(error) => {
                 let error_info = classify_error(&error);
                 last_error = Some(error_info.clone());
-                
+
                 log!("❌ Attempt {} failed: {} ({:?})", attempt, error_info.message, error_info.error_type);
 
                 // Don't retry permanent errors
@@ -417,13 +660,13 @@ fn fetch_with_intelligent_retry(symbol: &str, source: DataSource, config: &Retry
                     break;
                 }
 
-                // Calculate delay for next retry
+                // Calculate delay for next retry using decorrelated jitter,
+                // then actually honor it instead of just logging it.
                 if attempt < config.max_attempts {
-                    let delay = calculate_retry_delay(attempt, config, &error_info);
-                    log!("⏳ Waiting {}ms before retry...", delay);
-                    
-                    // Simple delay simulation (in real implementation, you'd use proper sleep)
-                    // For now, just log the delay
+                    let delay = calculate_decorrelated_jitter_delay(prev_delay_ms, config, &error_info, symbol, attempt);
+                    prev_delay_ms = delay;
+                    log!("⏳ Waiting {}ms before retry (decorrelated jitter)...", delay);
+                    wait(delay);
                 }
             }
         }
@@ -437,156 +680,263 @@ fn fetch_with_intelligent_retry(symbol: &str, source: DataSource, config: &Retry
     }))
 }
 
-fn classify_error(error: &anyhow::Error) -> ErrorInfo {
-    let error_str = error.to_string().to_lowercase();
-    
-    // Rate limiting errors
-    if error_str.contains("rate limit") || error_str.contains("too many requests") || error_str.contains("429") {
-        return ErrorInfo {
-            error_type: ErrorType::RateLimit,
-            message: "Rate limit exceeded".to_string(),
-            retry_after: Some(60), // Wait 1 minute for rate limits
-        };
+/// Busy-waits for roughly `ms` milliseconds. The VM this oracle runs in has
+/// no wall-clock sleep syscall, so backoff has to be honored by spinning on a
+/// monotonic clock read instead.
+fn wait(ms: u32) {
+    if ms == 0 {
+        return;
+    }
+    let start = std::time::SystemTime::now();
+    loop {
+        match start.elapsed() {
+            Ok(elapsed) if elapsed.as_millis() >= ms as u128 => break,
+            Ok(_) => continue,
+            Err(_) => break, // clock went backwards; don't spin forever
+        }
     }
+}
 
-    // Network timeout errors
-    if error_str.contains("timeout") || error_str.contains("connection") || error_str.contains("network") {
-        return ErrorInfo {
-            error_type: ErrorType::Timeout,
-            message: "Network timeout or connection issue".to_string(),
-            retry_after: Some(5),
-        };
-    }
+/// Decorrelated jitter backoff (AWS's "Exponential Backoff And Jitter"
+/// scheme): `delay = min(max_delay_ms, random_between(base_delay_ms, prev*3))`.
+/// Spreads retries across nodes instead of exponential backoff's lockstep
+/// retry storms. A provider-supplied `retry_after` still acts as a floor.
+fn calculate_decorrelated_jitter_delay(prev_delay_ms: u32, config: &RetryConfig, error_info: &ErrorInfo, symbol: &str, attempt: u32) -> u32 {
+    let upper = (prev_delay_ms as u64 * 3).max(config.base_delay_ms as u64);
+    let seed = jitter_seed(symbol, attempt);
+    let jittered = random_between(config.base_delay_ms as u64, upper, seed) as u32;
+    let capped = jittered.min(config.max_delay_ms);
 
-    // HTTP errors that might be transient
-    if error_str.contains("http error: 5") || error_str.contains("internal server error") {
-        return ErrorInfo {
-            error_type: ErrorType::Transient,
-            message: "Server error (transient)".to_string(),
-            retry_after: Some(10),
-        };
+    match error_info.retry_after {
+        Some(retry_after_secs) => capped.max(retry_after_secs.saturating_mul(1000)),
+        None => capped,
     }
+}
 
-    // Symbol not found or invalid - permanent
-    if error_str.contains("not found") || error_str.contains("invalid symbol") || error_str.contains("empty response") {
-        return ErrorInfo {
-            error_type: ErrorType::Permanent,
-            message: "Symbol not found or invalid".to_string(),
-            retry_after: None,
-        };
-    }
+/// Mixes the symbol being fetched, the retry attempt number, and the current
+/// time into a seed for `random_between`. Retry timing only needs to look
+/// random across nodes -- it isn't part of tally-phase consensus -- so a
+/// lightweight non-cryptographic mix is enough here.
+fn jitter_seed(symbol: &str, attempt: u32) -> u64 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    let symbol_hash = symbol.bytes().fold(0u64, |acc, b| acc.wrapping_mul(31).wrapping_add(b as u64));
+    nanos ^ symbol_hash.wrapping_mul(0x9E3779B97F4A7C15) ^ (attempt as u64)
+}
 
-    // JSON parsing errors - might be transient
-    if error_str.contains("json") || error_str.contains("parse") {
-        return ErrorInfo {
-            error_type: ErrorType::Transient,
-            message: "Data parsing error".to_string(),
-            retry_after: Some(3),
-        };
-    }
+/// Xorshift64-based `[low, high]` integer draw. Not suitable for anything
+/// consensus-critical, just for spreading retry delays.
+fn random_between(low: u64, high: u64, seed: u64) -> u64 {
+    if high <= low {
+        return low;
+    }
+    let mut x = seed ^ 0x9E3779B97F4A7C15;
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    low + (x % (high - low + 1))
+}
 
-    // Default to transient for unknown errors
-    ErrorInfo {
-        error_type: ErrorType::Transient,
-        message: error.to_string(),
-        retry_after: Some(5),
+/// Classifies a `FetchError` into a retry decision using the real signals
+/// captured at the fetch boundary (status code, provider, parsed
+/// `Retry-After`) instead of pattern-matching the error's rendered message.
+fn classify_error(error: &FetchError) -> ErrorInfo {
+    // A provider-sent Retry-After always wins as the lower bound for the delay.
+    let retry_after_floor = error.retry_after_header;
+
+    match &error.kind {
+        FetchErrorKind::ApiError(msg) => {
+            // Payload-level rate limiting, e.g. Alpha Vantage returning HTTP 200
+            // with `{"Note": "...call frequency..."}` instead of a 429.
+            if is_rate_limit_payload(&error.body) {
+                return ErrorInfo {
+                    error_type: ErrorType::RateLimit,
+                    message: format!("{:?} rate limit (payload): {}", error.source, msg),
+                    retry_after: Some(retry_after_floor.unwrap_or(60)),
+                };
+            }
+            ErrorInfo {
+                error_type: ErrorType::Permanent,
+                message: format!("{:?} API error: {}", error.source, msg),
+                retry_after: None,
+            }
+        }
+        FetchErrorKind::Decode(msg) => ErrorInfo {
+            error_type: ErrorType::Transient,
+            message: format!("{:?} decode error: {}", error.source, msg),
+            retry_after: Some(retry_after_floor.unwrap_or(3)),
+        },
+        FetchErrorKind::Transport => match error.status {
+            429 => ErrorInfo {
+                error_type: ErrorType::RateLimit,
+                message: format!("{:?} rate limited (HTTP 429)", error.source),
+                retry_after: Some(retry_after_floor.unwrap_or(60)),
+            },
+            408 | 504 => ErrorInfo {
+                error_type: ErrorType::Timeout,
+                message: format!("{:?} timed out (HTTP {})", error.source, error.status),
+                retry_after: Some(retry_after_floor.unwrap_or(5)),
+            },
+            500..=599 => ErrorInfo {
+                error_type: ErrorType::Transient,
+                message: format!("{:?} server error (HTTP {})", error.source, error.status),
+                retry_after: Some(retry_after_floor.unwrap_or(10)),
+            },
+            404 | 400 | 401 | 403 => ErrorInfo {
+                error_type: ErrorType::Permanent,
+                message: format!("{:?} rejected the request (HTTP {})", error.source, error.status),
+                retry_after: None,
+            },
+            0 => ErrorInfo {
+                error_type: ErrorType::Timeout,
+                message: format!("{:?} transport error (no status, likely connection/network issue)", error.source),
+                retry_after: Some(retry_after_floor.unwrap_or(5)),
+            },
+            _ => ErrorInfo {
+                error_type: ErrorType::Transient,
+                message: format!("{:?} HTTP error: {}", error.source, error.status),
+                retry_after: Some(retry_after_floor.unwrap_or(5)),
+            },
+        },
     }
 }
 
-fn calculate_retry_delay(attempt: u32, config: &RetryConfig, error_info: &ErrorInfo) -> u32 {
-    // Use error-specific delay if provided
-    if let Some(retry_after) = error_info.retry_after {
-        return retry_after * 1000; // Convert to milliseconds
-    }
+/// Detects provider payload bodies that report a rate limit via HTTP 200,
+/// e.g. Alpha Vantage's `{"Note": "...call frequency..."}` / `{"Information": "..."}`.
+fn is_rate_limit_payload(body: &str) -> bool {
+    let lower = body.to_lowercase();
+    lower.contains("call frequency") || lower.contains("rate limit") || lower.contains("too many requests")
+}
 
-    // Calculate exponential backoff
-    let delay = if config.exponential_backoff {
-        config.base_delay_ms * (2_u32.pow(attempt - 1))
+/// Sample median of a slice of prices (average of the two middle values on
+/// an even count).
+fn median_of(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = sorted.len();
+    if n % 2 == 1 {
+        sorted[n / 2]
     } else {
-        config.base_delay_ms
-    };
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+    }
+}
 
-    // Clamp to max delay
-    delay.min(config.max_delay_ms)
+/// Modified z-scores (Iglewicz & Hoaglin): `0.6745 * (x_i - median) / MAD`.
+/// Returns `(median, MAD, per-point scores)`. With only a handful of
+/// samples, a single bad source drags a plain mean and inflates a plain
+/// std-dev enough that its own z-score rarely crosses threshold -- the
+/// median and median-absolute-deviation aren't pulled around by one
+/// outlier the way the mean and std-dev are. The degenerate `MAD == 0`
+/// case (all prices equal, or all-but-one) scores every point 0 rather than
+/// dividing by zero.
+fn modified_z_scores(prices: &[f64]) -> (f64, f64, Vec<f64>) {
+    let median = median_of(prices);
+    let deviations: Vec<f64> = prices.iter().map(|p| (p - median).abs()).collect();
+    let mad = median_of(&deviations);
+
+    if mad == 0.0 {
+        return (median, 0.0, vec![0.0; prices.len()]);
+    }
+
+    let scores = prices.iter().map(|p| 0.6745 * (p - median) / mad).collect();
+    (median, mad, scores)
 }
 
 fn cross_validate_three_sources(av_result: PriceResult, fmp_result: PriceResult, td_result: PriceResult) -> PriceResult {
     let prices = vec![av_result.price, fmp_result.price, td_result.price];
     let sources = vec![&av_result.source, &fmp_result.source, &td_result.source];
-    
+    let sessions = [av_result.session, fmp_result.session, td_result.session];
+
     log!("🔍 Triple source validation: AV({}), FMP({}), TD({})", av_result.price, fmp_result.price, td_result.price);
-    
-    // Calculate statistical measures
-    let mean_price = prices.iter().sum::<f64>() / prices.len() as f64;
-    let variance = prices.iter().map(|p| (p - mean_price).powi(2)).sum::<f64>() / prices.len() as f64;
-    let std_dev = variance.sqrt();
-    let cv = std_dev / mean_price; // Coefficient of variation
-    
+    log!("   • Sessions: AV={}, FMP={}, TD={}", sessions[0].as_str(), sessions[1].as_str(), sessions[2].as_str());
+
+    let (median_price, mad, modified_z) = modified_z_scores(&prices);
+    let dispersion = if median_price != 0.0 { mad / median_price } else { 0.0 };
+
+    // Sources disagreeing on which part of the trading day it is (e.g. one
+    // feed reports a pre-market tick while another is still on yesterday's
+    // regular-session close) isn't evidence of a bad price -- it's a data
+    // staleness mismatch. Widen the outlier tolerance rather than flagging a
+    // legitimate pre/post-market divergence as a statistical outlier.
+    let sessions_diverge = sessions.iter().any(|s| *s != sessions[0]);
+    let z_threshold = if sessions_diverge { 5.0 } else { 3.5 };
+    if sessions_diverge {
+        log!("   ⏱️ Sessions diverge across sources, widening outlier threshold to {:.1}", z_threshold);
+    }
+
     // Enhanced outlier detection with three sources
     let mut valid_prices = Vec::new();
     let mut valid_sources = Vec::new();
-    let mut confidence_scores = Vec::new();
-    
-    for (i, &price) in prices.iter().enumerate() {
-        let z_score = (price - mean_price) / std_dev;
-        
-        // More lenient outlier detection with 3 sources (2.0 instead of 1.5)
-        if z_score.abs() <= 2.0 {
-            valid_prices.push(price);
-            valid_sources.push(sources[i]);
-            
-            // Calculate confidence based on z-score
-            let z_confidence = ((2.0 - z_score.abs()) / 2.0 * 100.0) as u8;
-            confidence_scores.push(z_confidence);
-        } else {
-            log!("⚠️ Outlier detected: {} with z-score: {:.2}", sources[i], z_score);
+
+    if mad == 0.0 {
+        // All prices agree exactly -- nothing to flag.
+        valid_prices = prices.clone();
+        valid_sources = sources.clone();
+    } else {
+        for (i, &price) in prices.iter().enumerate() {
+            if modified_z[i].abs() <= z_threshold {
+                valid_prices.push(price);
+                valid_sources.push(sources[i]);
+            } else {
+                log!("⚠️ Outlier detected: {} with modified z-score: {:.2}", sources[i], modified_z[i]);
+            }
         }
     }
-    
+
     // If all sources are valid (no outliers)
     if valid_prices.len() == 3 {
-        let final_confidence = if cv < 0.01 { // Very low variation
+        let mut final_confidence = if mad == 0.0 { // Exact agreement
+            99
+        } else if dispersion < 0.01 { // Very low dispersion
             95
-        } else if cv < 0.02 { // Low variation
+        } else if dispersion < 0.02 { // Low dispersion
             90
-        } else if cv < 0.05 { // Moderate variation
+        } else if dispersion < 0.05 { // Moderate dispersion
             85
-        } else { // High variation
+        } else { // High dispersion
             75
         };
-        
-        log!("✅ All three sources validated. CV: {:.4}, Final confidence: {}%", cv, final_confidence);
-        
+        if sessions_diverge {
+            final_confidence = final_confidence.saturating_sub(10);
+        }
+
+        log!("✅ All three sources validated. MAD: {:.4}, dispersion: {:.4}, Final confidence: {}%", mad, dispersion, final_confidence);
+
         PriceResult {
-            price: mean_price,
-            source: format!("Triple-Validated: {} + {} + {} (±{:.4})", sources[0], sources[1], sources[2], std_dev),
+            price: median_price,
+            source: format!("Triple-Validated: {} + {} + {} (MAD={:.4})", sources[0], sources[1], sources[2], mad),
             confidence: final_confidence,
             timestamp: av_result.timestamp,
-            metadata: Some(format!("prices=[{:.4}, {:.4}, {:.4}], std_dev={:.4}, cv={:.4}", 
-                prices[0], prices[1], prices[2], std_dev, cv)),
+            metadata: Some(format!("prices=[{:.4}, {:.4}, {:.4}], median={:.4}, mad={:.4}, dispersion={:.4}, sessions=[{}, {}, {}]",
+                prices[0], prices[1], prices[2], median_price, mad, dispersion, sessions[0].as_str(), sessions[1].as_str(), sessions[2].as_str())),
             error_info: None,
+            session: if sessions_diverge { TradeSession::Closed } else { sessions[0] },
+            currency: av_result.currency.clone(),
         }
     } else if valid_prices.len() == 2 {
         // Two sources agree, one is outlier
-        let mean_valid = valid_prices.iter().sum::<f64>() / valid_prices.len() as f64;
-        let confidence = confidence_scores.iter().sum::<u8>() / confidence_scores.len() as u8;
-        let adjusted_confidence = (confidence as f64 * 0.9) as u8; // Slight reduction for only 2 sources
-        
-        log!("✅ Two sources validated (one outlier removed). Mean: {:.4}, Confidence: {}%", mean_valid, adjusted_confidence);
-        
+        let median_valid = median_of(&valid_prices);
+        let adjusted_confidence = ((1.0 - dispersion.min(1.0)) * 90.0) as u8; // Slight reduction for only 2 sources
+
+        log!("✅ Two sources validated (one outlier removed). Median: {:.4}, Confidence: {}%", median_valid, adjusted_confidence);
+
         PriceResult {
-            price: mean_valid,
+            price: median_valid,
             source: format!("Dual-Validated: {} + {} (1 outlier excluded)", valid_sources[0], valid_sources[1]),
             confidence: adjusted_confidence,
             timestamp: av_result.timestamp,
-            metadata: Some(format!("validated_prices=[{:.4}, {:.4}], excluded_count=1", 
-                valid_prices[0], valid_prices[1])),
+            metadata: Some(format!("validated_prices=[{:.4}, {:.4}], excluded_count=1, mad={:.4}, sessions=[{}, {}, {}]",
+                valid_prices[0], valid_prices[1], mad, sessions[0].as_str(), sessions[1].as_str(), sessions[2].as_str())),
             error_info: None,
+            session: if sessions_diverge { TradeSession::Closed } else { sessions[0] },
+            currency: av_result.currency.clone(),
         }
     } else {
         // All sources are outliers or only one valid - fallback to best single source
-        log!("⚠️ Excessive variation detected, falling back to best single source");
-        
+        log!("⚠️ Excessive dispersion detected, falling back to best single source");
+
         // Choose the source with highest confidence from original results
         let best_result = if av_result.confidence >= fmp_result.confidence && av_result.confidence >= td_result.confidence {
             av_result
@@ -595,8 +945,8 @@ fn cross_validate_three_sources(av_result: PriceResult, fmp_result: PriceResult,
         } else {
             td_result
         };
-        
-        enhance_single_source_result(best_result, format!("High variation detected (CV: {:.4}), using best single source", cv))
+
+        enhance_single_source_result(best_result, format!("High dispersion detected (MAD: {:.4}), using best single source", mad))
     }
 }
 
@@ -604,13 +954,14 @@ fn cross_validate_two_sources_av_td(av_result: PriceResult, td_result: PriceResu
     let price_diff = (av_result.price - td_result.price).abs();
     let avg_price = (av_result.price + td_result.price) / 2.0;
     let percentage_diff = (price_diff / avg_price) * 100.0;
-    
+    let session = combined_session(&[av_result.session, td_result.session]);
+
     log!("🔍 AV-TD validation: AV({}), TD({}), diff: {:.2}%", av_result.price, td_result.price, percentage_diff);
-    
+
     if percentage_diff <= 2.0 { // Very close prices
         let weighted_price = (av_result.price * 0.6) + (td_result.price * 0.4); // Slight preference for AV
         let confidence = ((av_result.confidence + td_result.confidence) / 2) as u8;
-        
+
         PriceResult {
             price: weighted_price,
             source: format!("AV-TD Validated: {} + {}", av_result.source, td_result.source),
@@ -618,11 +969,13 @@ fn cross_validate_two_sources_av_td(av_result: PriceResult, td_result: PriceResu
             timestamp: av_result.timestamp,
             metadata: Some(format!("price_diff={:.4}, percentage_diff={:.2}%", price_diff, percentage_diff)),
             error_info: None,
+            session,
+            currency: av_result.currency.clone(),
         }
     } else if percentage_diff <= 5.0 { // Moderate difference
         let weighted_price = (av_result.price * 0.7) + (td_result.price * 0.3); // Prefer AV more
         let confidence = ((av_result.confidence + td_result.confidence) / 2) as u8;
-        
+
         PriceResult {
             price: weighted_price,
             source: format!("AV-TD Moderate: {} + {} (±{:.2}%)", av_result.source, td_result.source, percentage_diff),
@@ -630,6 +983,8 @@ fn cross_validate_two_sources_av_td(av_result: PriceResult, td_result: PriceResu
             timestamp: av_result.timestamp,
             metadata: Some(format!("price_diff={:.4}, percentage_diff={:.2}%", price_diff, percentage_diff)),
             error_info: Some("Moderate price variance between sources".to_string()),
+            session,
+            currency: av_result.currency.clone(),
         }
     } else { // Large difference - use higher confidence source
         if av_result.confidence >= td_result.confidence {
@@ -644,13 +999,14 @@ fn cross_validate_two_sources_fmp_td(fmp_result: PriceResult, td_result: PriceRe
     let price_diff = (fmp_result.price - td_result.price).abs();
     let avg_price = (fmp_result.price + td_result.price) / 2.0;
     let percentage_diff = (price_diff / avg_price) * 100.0;
-    
+    let session = combined_session(&[fmp_result.session, td_result.session]);
+
     log!("🔍 FMP-TD validation: FMP({}), TD({}), diff: {:.2}%", fmp_result.price, td_result.price, percentage_diff);
-    
+
     if percentage_diff <= 2.0 { // Very close prices
         let weighted_price = (fmp_result.price * 0.6) + (td_result.price * 0.4); // Slight preference for FMP
         let confidence = ((fmp_result.confidence + td_result.confidence) / 2) as u8;
-        
+
         PriceResult {
             price: weighted_price,
             source: format!("FMP-TD Validated: {} + {}", fmp_result.source, td_result.source),
@@ -658,11 +1014,13 @@ fn cross_validate_two_sources_fmp_td(fmp_result: PriceResult, td_result: PriceRe
             timestamp: fmp_result.timestamp,
             metadata: Some(format!("price_diff={:.4}, percentage_diff={:.2}%", price_diff, percentage_diff)),
             error_info: None,
+            session,
+            currency: fmp_result.currency.clone(),
         }
     } else if percentage_diff <= 5.0 { // Moderate difference
         let weighted_price = (fmp_result.price * 0.7) + (td_result.price * 0.3); // Prefer FMP more
         let confidence = ((fmp_result.confidence + td_result.confidence) / 2) as u8;
-        
+
         PriceResult {
             price: weighted_price,
             source: format!("FMP-TD Moderate: {} + {} (±{:.2}%)", fmp_result.source, td_result.source, percentage_diff),
@@ -670,6 +1028,8 @@ fn cross_validate_two_sources_fmp_td(fmp_result: PriceResult, td_result: PriceRe
             timestamp: fmp_result.timestamp,
             metadata: Some(format!("price_diff={:.4}, percentage_diff={:.2}%", price_diff, percentage_diff)),
             error_info: Some("Moderate price variance between sources".to_string()),
+            session,
+            currency: fmp_result.currency.clone(),
         }
     } else { // Large difference - use higher confidence source
         if fmp_result.confidence >= td_result.confidence {
@@ -680,61 +1040,88 @@ fn cross_validate_two_sources_fmp_td(fmp_result: PriceResult, td_result: PriceRe
     }
 }
 
-fn fetch_twelve_data_price(symbol: &str) -> Result<PriceResult> {
+fn fetch_twelve_data_price(symbol: &str) -> Result<PriceResult, FetchError> {
     let api_key = "28d73aeebb4a4059b8ccd7b0d7e7a5a6";
     let url = format!("https://api.twelvedata.com/quote?symbol={}&apikey={}", symbol, api_key);
-    
+
     log!("🌐 Fetching from TwelveData: {}", url);
-    
+
     let response = http_fetch(url, None);
+    let retry_after = response.headers.iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+        .and_then(|(_, value)| value.parse::<u32>().ok());
 
     if !response.is_ok() {
-        return Err(anyhow::anyhow!("TwelveData HTTP error: {}", response.status));
+        return Err(FetchError::transport(DataSource::TwelveData, response.status, &response.bytes, retry_after));
     }
-    
+
     let response_data: TwelveDataResponse = serde_json::from_slice(&response.bytes)
-        .map_err(|e| anyhow::anyhow!("Failed to parse TwelveData response: {}", e))?;
-    
+        .map_err(|e| FetchError::decode(DataSource::TwelveData, &response.bytes, format!("Failed to parse TwelveData response: {}", e)))?;
+
     let price = response_data.close.parse::<f64>()
-        .map_err(|e| anyhow::anyhow!("Failed to parse price: {}", e))?;
-    
+        .map_err(|e| FetchError::decode(DataSource::TwelveData, &response.bytes, format!("Failed to parse price: {}", e)))?;
+
     // Basic sanity check
     if price <= 0.0 || price > 1000000.0 {
-        return Err(anyhow::anyhow!("TwelveData price out of reasonable range: {}", price));
+        return Err(FetchError::api(DataSource::TwelveData, response.status, &response.bytes, format!("price out of reasonable range: {}", price)));
     }
-    
-    let confidence = if response_data.is_market_open {
-        88 // High confidence during market hours
-    } else {
-        82 // Slightly lower confidence outside market hours
+
+    let session = resolve_twelve_data_session(response_data.is_market_open, response_data.timestamp);
+    let mut confidence = match session {
+        TradeSession::Regular => 88, // High confidence during market hours
+        TradeSession::PreMarket | TradeSession::PostMarket => 82, // Slightly lower outside regular hours
+        TradeSession::Closed | TradeSession::Halted => 70, // Materially lower when the quote isn't live
     };
-    
-    log!("✅ TwelveData price for {}: {} (confidence: {}%)", symbol, price, confidence);
-    
+
+    let mut metadata = format!("volume={}, market_open={}, change={}, session={}, currency={}",
+        response_data.volume, response_data.is_market_open, response_data.change, session.as_str(), response_data.currency);
+
+    // Depth/spread, when the plan reports it: a pathologically wide spread
+    // or a last price outside the current book is penalized even if every
+    // source agrees on the price itself.
+    if let (Some(bid_raw), Some(ask_raw)) = (&response_data.bid, &response_data.ask) {
+        if let (Ok(bid), Ok(ask)) = (bid_raw.parse::<f64>(), ask_raw.parse::<f64>()) {
+            let spread = relative_spread(bid, ask);
+            metadata.push_str(&format!(", bid={:.4}, ask={:.4}, spread={:.4}", bid, ask, spread));
+            confidence = adjust_confidence_for_spread(confidence, spread);
+            if is_price_outside_book(price, bid, ask) {
+                log!("⚠️ TwelveData spot price {} outside book [{:.4}, {:.4}]", price, bid, ask);
+                metadata.push_str(", price_outside_book=true");
+                confidence = confidence.saturating_sub(15);
+            }
+        }
+    }
+
+    log!("✅ TwelveData price for {}: {} (confidence: {}%, session: {})", symbol, price, confidence, session.as_str());
+
     Ok(PriceResult {
         price,
         source: format!("TwelveData-{}", response_data.exchange),
         confidence,
         timestamp: response_data.datetime,
-        metadata: Some(format!("volume={}, market_open={}, change={}", 
-            response_data.volume, response_data.is_market_open, response_data.change)),
+        metadata: Some(metadata),
         error_info: None,
+        session,
+        currency: response_data.currency,
     })
 }
 
-fn fetch_twelve_data_price_relaxed(symbol: &str) -> Result<PriceResult> {
+fn fetch_twelve_data_price_relaxed(symbol: &str) -> Result<PriceResult, FetchError> {
     let api_key = "28d73aeebb4a4059b8ccd7b0d7e7a5a6";
     let url = format!("https://api.twelvedata.com/quote?symbol={}&apikey={}", symbol, api_key);
-    
+
     log!("🌐 Fetching from TwelveData (relaxed): {}", url);
-    
+
     // Use the same http_fetch pattern with more lenient error handling
     let response = http_fetch(url, None);
+    let retry_after = response.headers.iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+        .and_then(|(_, value)| value.parse::<u32>().ok());
 
     if !response.is_ok() {
-        return Err(anyhow::anyhow!("TwelveData HTTP error (relaxed): {}", response.status));
+        return Err(FetchError::transport(DataSource::TwelveData, response.status, &response.bytes, retry_after));
     }
-    
+
     // Try to parse with fallback handling
     match serde_json::from_slice::<TwelveDataResponse>(&response.bytes) {
         Ok(response_data) => {
@@ -742,28 +1129,142 @@ fn fetch_twelve_data_price_relaxed(symbol: &str) -> Result<PriceResult> {
                 Ok(price) => {
                     // Relaxed sanity check
                     if price <= 0.0 || price > 10000000.0 { // More lenient range
-                        return Err(anyhow::anyhow!("TwelveData price out of range (relaxed): {}", price));
+                        return Err(FetchError::api(DataSource::TwelveData, response.status, &response.bytes, format!("price out of range (relaxed): {}", price)));
                     }
-                    
+
                     let confidence = 70; // Lower confidence in relaxed mode
-                    
-                    log!("✅ TwelveData relaxed price for {}: {} (confidence: {}%)", symbol, price, confidence);
-                    
+                    let session = resolve_twelve_data_session(response_data.is_market_open, response_data.timestamp);
+
+                    log!("✅ TwelveData relaxed price for {}: {} (confidence: {}%, session: {})", symbol, price, confidence, session.as_str());
+
                     Ok(PriceResult {
                         price,
                         source: format!("TwelveData-{}-Relaxed", response_data.exchange),
                         confidence,
                         timestamp: response_data.datetime,
-                        metadata: Some(format!("relaxed_mode=true, volume={}", response_data.volume)),
+                        metadata: Some(format!("relaxed_mode=true, volume={}, session={}, currency={}", response_data.volume, session.as_str(), response_data.currency)),
                         error_info: Some("Relaxed mode - reduced validation".to_string()),
+                        session,
+                        currency: response_data.currency,
                     })
                 }
-                Err(e) => Err(anyhow::anyhow!("Failed to parse price in relaxed mode: {}", e))
+                Err(e) => Err(FetchError::decode(DataSource::TwelveData, &response.bytes, format!("Failed to parse price in relaxed mode: {}", e)))
             }
         }
         Err(e) => {
             log!("⚠️ TwelveData relaxed mode: Failed to parse JSON, trying fallback");
-            Err(anyhow::anyhow!("JSON parse failed in relaxed mode: {}", e))
+            Err(FetchError::decode(DataSource::TwelveData, &response.bytes, format!("JSON parse failed in relaxed mode: {}", e)))
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct TwelveDataPriceOnly {
+    price: String,
+}
+
+/// Fetches a single FX spot rate (e.g. "EUR" -> "USD") via TwelveData's
+/// lightweight `/price` endpoint -- an FX conversion needs nothing but a
+/// number, so there's no reason to pull a full quote for it.
+fn fetch_fx_rate(from_currency: &str, to_currency: &str) -> Result<f64, FetchError> {
+    let api_key = "28d73aeebb4a4059b8ccd7b0d7e7a5a6";
+    let pair = format!("{}/{}", from_currency.to_uppercase(), to_currency.to_uppercase());
+    let api_url = format!("https://api.twelvedata.com/price?symbol={}&apikey={}", pair, api_key);
+
+    log!("🌐 Fetching FX rate from TwelveData: {}", api_url);
+
+    let response = http_fetch(api_url, None);
+    let retry_after = response.headers.iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+        .and_then(|(_, value)| value.parse::<u32>().ok());
+
+    if !response.is_ok() {
+        return Err(FetchError::transport(DataSource::TwelveData, response.status, &response.bytes, retry_after));
+    }
+
+    let data = serde_json::from_slice::<TwelveDataPriceOnly>(&response.bytes)
+        .map_err(|e| FetchError::decode(DataSource::TwelveData, &response.bytes, format!("Failed to parse FX rate response: {}", e)))?;
+
+    let rate = data.price.parse::<f64>()
+        .map_err(|e| FetchError::decode(DataSource::TwelveData, &response.bytes, format!("Failed to parse FX rate: {}", e)))?;
+
+    if rate <= 0.0 {
+        return Err(FetchError::api(DataSource::TwelveData, response.status, &response.bytes, format!("FX rate out of range: {}", rate)));
+    }
+
+    Ok(rate)
+}
+
+/// Same decorrelated-jitter retry loop as `fetch_with_intelligent_retry`,
+/// applied to the single-value FX lookup rather than a full price fetch.
+fn fetch_fx_rate_with_retry(from_currency: &str, to_currency: &str, config: &RetryConfig) -> Result<f64, ErrorInfo> {
+    if from_currency.eq_ignore_ascii_case(to_currency) {
+        return Ok(1.0);
+    }
+
+    let mut attempt = 0;
+    let mut last_error: Option<ErrorInfo> = None;
+    let mut prev_delay_ms = config.base_delay_ms;
+    let pair = format!("{}{}", from_currency, to_currency);
+
+    while attempt < config.max_attempts {
+        attempt += 1;
+
+        match fetch_fx_rate(from_currency, to_currency) {
+            Ok(rate) => return Ok(rate),
+            Err(error) => {
+                let error_info = classify_error(&error);
+                last_error = Some(error_info.clone());
+
+                if matches!(error_info.error_type, ErrorType::Permanent) {
+                    break;
+                }
+
+                if attempt < config.max_attempts {
+                    let delay = calculate_decorrelated_jitter_delay(prev_delay_ms, config, &error_info, &pair, attempt);
+                    prev_delay_ms = delay;
+                    wait(delay);
+                }
+            }
+        }
+    }
+
+    Err(last_error.unwrap_or(ErrorInfo {
+        error_type: ErrorType::Permanent,
+        message: "Unknown error fetching FX rate".to_string(),
+        retry_after: None,
+    }))
+}
+
+/// Converts a `PriceResult` into `target_currency` before it's fed into any
+/// statistical cross-validation, so mean/median/z-score comparisons across
+/// sources never silently mix currencies. A failed/missing FX rate degrades
+/// confidence rather than dropping the source entirely.
+fn normalize_to_target_currency(mut result: PriceResult, target_currency: &str, config: &RetryConfig) -> PriceResult {
+    if result.currency.eq_ignore_ascii_case(target_currency) {
+        return result;
+    }
+
+    match fetch_fx_rate_with_retry(&result.currency, target_currency, config) {
+        Ok(rate) => {
+            let native_price = result.price;
+            let native_currency = result.currency.clone();
+            result.price *= rate;
+            result.currency = target_currency.to_string();
+            result.metadata = Some(format!(
+                "{}, native_price={:.4} {}, fx_rate={:.6} ({}->{})",
+                result.metadata.unwrap_or_default(), native_price, native_currency, rate, native_currency, target_currency
+            ));
+            result
+        }
+        Err(error_info) => {
+            log!("⚠️ FX rate fetch failed for {}->{}: {} -- keeping native price, penalizing confidence", result.currency, target_currency, error_info.message);
+            result.confidence = result.confidence.saturating_sub(15);
+            result.metadata = Some(format!(
+                "{}, fx_rate_unavailable={}->{}",
+                result.metadata.unwrap_or_default(), result.currency, target_currency
+            ));
+            result
         }
     }
 }
@@ -819,6 +1320,16 @@ fn validate_and_normalize_symbol(input: &str) -> Result<ValidationResult, String
         return Err("Input too long (max 50 characters)".to_string());
     }
 
+    // Step 1.4: Pull off a "SYMBOL@PERIOD" candlestick-period override, if
+    // present, before any other parsing sees the '@'.
+    let (trimmed, candlestick_period) = extract_candlestick_period_override(trimmed);
+    let trimmed = trimmed.as_str();
+
+    // Step 1.5: Pull off a "SYMBOL/CCY" target-currency override, if present,
+    // before any other parsing sees the slash.
+    let (trimmed, target_currency) = extract_target_currency_override(trimmed);
+    let trimmed = trimmed.as_str();
+
     // Step 2: Remove common prefixes and suffixes
     let cleaned = remove_common_patterns(trimmed);
     
@@ -828,6 +1339,31 @@ fn validate_and_normalize_symbol(input: &str) -> Result<ValidationResult, String
         warnings.push(format!("Removed exchange prefix: {}", exchange_info.unwrap()));
     }
 
+    // Step 3.5: Recognize crypto trading pairs (e.g. "BTCUSDT", "btc-usd",
+    // "ETH/USDC") and normalize them into canonical BASE-QUOTE form before
+    // the equity-oriented format/fuzzy-matching checks below, which don't
+    // expect a quote-currency suffix or separator.
+    if let Some(pair) = parse_crypto_pair(&symbol_part, DEFAULT_QUOTE_CURRENCIES) {
+        let final_symbol = pair.canonical();
+        if final_symbol != symbol_part.to_uppercase() {
+            warnings.push(format!("Recognized crypto pair: {} -> {}", symbol_part, final_symbol));
+        }
+
+        let confidence = calculate_input_confidence(&original_input, &final_symbol, &warnings);
+        let normalization_applied = original_input.trim().to_uppercase() != final_symbol;
+
+        return Ok(ValidationResult {
+            validated_symbol: final_symbol,
+            original_input,
+            confidence,
+            normalization_applied,
+            fuzzy_match: None,
+            warnings,
+            target_currency,
+            candlestick_period,
+        });
+    }
+
     // Step 4: Normalize case and validate format
     let normalized = symbol_part.to_uppercase();
     
@@ -859,15 +1395,168 @@ fn validate_and_normalize_symbol(input: &str) -> Result<ValidationResult, String
         normalization_applied,
         fuzzy_match,
         warnings,
+        target_currency,
+        candlestick_period,
     })
 }
 
-fn remove_common_patterns(input: &str) -> String {
-    let mut cleaned = input.to_string();
-    
-    // Remove common prefixes/suffixes that users might add
-    let patterns_to_remove = [
-        "$", "USD", "STOCK", "PRICE", "QUOTE", 
+/// Splits a trailing `/CCY` target-currency override off the raw input, e.g.
+/// "AAPL/EUR" -> ("AAPL", "EUR"). Defaults to USD when no override is given
+/// or the trailing segment isn't a plausible 3-letter ISO 4217 code.
+///
+/// Skips stripping entirely when `input` already parses as a crypto pair
+/// (e.g. "BTC/USD", "ETH/BTC") -- otherwise a 3-letter quote that's also a
+/// valid ISO currency code (`USD`, `EUR`, `GBP`, `BTC`, `ETH`) would be
+/// consumed here before `parse_crypto_pair` ever sees the slash, and the
+/// bare remainder is too short to be recovered by the no-separator
+/// bare-suffix heuristic.
+fn extract_target_currency_override(input: &str) -> (String, String) {
+    if parse_crypto_pair(input, DEFAULT_QUOTE_CURRENCIES).is_some() {
+        return (input.to_string(), "USD".to_string());
+    }
+
+    if let Some(slash_pos) = input.rfind('/') {
+        let symbol = &input[..slash_pos];
+        let currency = &input[slash_pos + 1..];
+        if currency.len() == 3 && currency.chars().all(|c| c.is_ascii_alphabetic()) {
+            return (symbol.to_string(), currency.to_uppercase());
+        }
+    }
+
+    (input.to_string(), "USD".to_string())
+}
+
+/// Splits a trailing `@PERIOD` candlestick-period override off the raw
+/// input, e.g. "AAPL@1h" -> ("AAPL", CandlestickPeriod::OneHour). Recognizes
+/// `1m`/`1min`, `5m`/`5min`, `1h`/`1hour`, `1d`/`1day` (case-insensitive) and
+/// defaults to `OneDay` when no override is given or the suffix isn't one of
+/// these. Uses `@` rather than `/` specifically so it can't collide with the
+/// `SYMBOL/CCY` currency-override syntax or any of `parse_crypto_pair`'s
+/// separators.
+fn extract_candlestick_period_override(input: &str) -> (String, CandlestickPeriod) {
+    if let Some(at_pos) = input.rfind('@') {
+        let symbol = &input[..at_pos];
+        let period = input[at_pos + 1..].to_lowercase();
+        let period = match period.as_str() {
+            "1m" | "1min" => Some(CandlestickPeriod::OneMinute),
+            "5m" | "5min" => Some(CandlestickPeriod::FiveMinute),
+            "1h" | "1hour" => Some(CandlestickPeriod::OneHour),
+            "1d" | "1day" => Some(CandlestickPeriod::OneDay),
+            _ => None,
+        };
+        if let Some(period) = period {
+            return (symbol.to_string(), period);
+        }
+    }
+
+    (input.to_string(), CandlestickPeriod::OneDay)
+}
+
+/// Quote currencies recognized when splitting a concatenated crypto symbol
+/// like `BTCUSDT`, checked longest-first so a symbol is split at the
+/// longest valid quote suffix (e.g. "USDT" wins over the "USD" it
+/// contains). Not exhaustive -- just the common ones seen in practice --
+/// but callers can pass their own extended set to `parse_crypto_pair`.
+const DEFAULT_QUOTE_CURRENCIES: &[&str] = &["USDT", "USDC", "BUSD", "USD", "EUR", "GBP", "BTC", "ETH"];
+
+/// A normalized crypto trading pair, e.g. `{ base: "BTC", quote: "USD" }`.
+struct CryptoPair {
+    base: String,
+    quote: String,
+}
+
+impl CryptoPair {
+    /// Canonical `BASE-QUOTE` form used both as the symbol sent to
+    /// providers and as the alternative-symbol suggestion (e.g. "BTC-USD").
+    fn canonical(&self) -> String {
+        format!("{}-{}", self.base, self.quote)
+    }
+}
+
+/// Minimum base-asset length accepted by the bare-suffix fallback in
+/// [`parse_crypto_pair`]. Real crypto bases are essentially never a single
+/// letter (`BTC`, `ETH`, `SOL`, `XRP`, ...), but plenty of real equity/ETF
+/// tickers happen to end in a recognized quote currency purely by
+/// coincidence -- e.g. `GBTC` (Grayscale Bitcoin Trust) ends in `BTC` with a
+/// one-letter remainder. Requiring at least two leftover characters keeps
+/// the heuristic from rewriting those into a bogus pair.
+const MIN_CRYPTO_BASE_LEN: usize = 2;
+
+/// Tries to parse `input` as a crypto trading pair, using `quote_currencies`
+/// to find the base/quote boundary in a concatenated symbol. Handles three
+/// shapes: already-separated (`BTC-USD`, `btc_usd`, `ETH/USDC`), and
+/// concatenated (`BTCUSDT`), split at the longest known quote suffix.
+/// Returns `None` if no separator is present and no known quote currency
+/// matches the end of the symbol, or the symbol is a known equity/ETF
+/// ticker, or the remaining base is too short to be a plausible crypto
+/// asset (see [`MIN_CRYPTO_BASE_LEN`]).
+fn parse_crypto_pair(input: &str, quote_currencies: &[&str]) -> Option<CryptoPair> {
+    let upper = input.to_uppercase();
+    let mut saw_separator = false;
+
+    for separator in ['-', '/', '_'] {
+        if let Some(pos) = upper.find(separator) {
+            saw_separator = true;
+            let base = &upper[..pos];
+            let quote = &upper[pos + 1..];
+            if base.is_empty() || quote.is_empty() {
+                continue;
+            }
+
+            // '/' doubles as the "SYMBOL/TARGET_CURRENCY" override syntax
+            // (e.g. "AAPL/EUR"), so a bare '/' split isn't enough signal on
+            // its own -- only accept it as a crypto pair when the quote is
+            // one of `quote_currencies` and the base isn't a known equity
+            // ticker. '-' and '_' aren't used by that syntax, so they stay
+            // unrestricted.
+            if separator == '/' {
+                let is_known_quote = quote_currencies.iter().any(|q| *q == quote);
+                let is_known_equity = KNOWN_SYMBOLS.contains(&base);
+                if !is_known_quote || is_known_equity {
+                    continue;
+                }
+            }
+
+            return Some(CryptoPair { base: base.to_string(), quote: quote.to_string() });
+        }
+    }
+
+    // A separator was present but didn't produce a match above (e.g. the
+    // rejected "AAPL/EUR" currency-override case) -- don't fall through to
+    // the bare-suffix heuristic below, since it assumes an unpunctuated
+    // symbol and would otherwise split on a leftover separator character.
+    if saw_separator {
+        return None;
+    }
+
+    // No explicit separator: only fall back to the bare-suffix heuristic
+    // once we know it isn't a recognized equity/ETF ticker, since that
+    // signal is a last resort and should never override a known symbol.
+    if KNOWN_SYMBOLS.contains(&upper.as_str()) {
+        return None;
+    }
+
+    let mut candidates: Vec<&&str> = quote_currencies.iter().collect();
+    candidates.sort_by_key(|q| std::cmp::Reverse(q.len()));
+
+    for quote in candidates {
+        if upper.len() > quote.len() && upper.ends_with(quote) {
+            let base = &upper[..upper.len() - quote.len()];
+            if base.len() >= MIN_CRYPTO_BASE_LEN {
+                return Some(CryptoPair { base: base.to_string(), quote: quote.to_string() });
+            }
+        }
+    }
+
+    None
+}
+
+fn remove_common_patterns(input: &str) -> String {
+    let mut cleaned = input.to_string();
+    
+    // Remove common prefixes/suffixes that users might add
+    let patterns_to_remove = [
+        "$", "USD", "STOCK", "PRICE", "QUOTE", 
         "GET", "FETCH", "SYMBOL", "TICKER",
         "(", ")", "[", "]", "{", "}", 
         "\"", "'", "`"
@@ -1066,7 +1755,7 @@ fn adjust_confidence_for_input(price_confidence: u8, validation: &ValidationResu
     combined.saturating_sub(warning_penalty).max(50)
 }
 
-fn fetch_alpha_vantage_price(symbol: &str) -> Result<PriceResult> {
+fn fetch_alpha_vantage_price(symbol: &str) -> Result<PriceResult, FetchError> {
     let api_key = "V7KH6L0VO80JQL5S";
     let api_url = format!(
         "https://www.alphavantage.co/query?function=GLOBAL_QUOTE&symbol={}&apikey={}",
@@ -1076,33 +1765,64 @@ fn fetch_alpha_vantage_price(symbol: &str) -> Result<PriceResult> {
     log!("🔍 Alpha Vantage API call: {}", api_url);
 
     let response = http_fetch(api_url, None);
+    let retry_after = response.headers.iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+        .and_then(|(_, value)| value.parse::<u32>().ok());
 
     if !response.is_ok() {
-        return Err(anyhow::anyhow!("Alpha Vantage HTTP error: {}", response.status));
+        return Err(FetchError::transport(DataSource::AlphaVantage, response.status, &response.bytes, retry_after));
+    }
+
+    // Alpha Vantage reports rate limits and bad symbols with HTTP 200 and a
+    // `{"Note": ...}` / `{"Information": ...}` / `{"Error Message": ...}`
+    // payload instead of a non-2xx status, so check for those before trying
+    // to decode the expected shape.
+    if let Ok(value) = serde_json::from_slice::<serde_json::Value>(&response.bytes) {
+        if let Some(obj) = value.as_object() {
+            for key in ["Note", "Information", "Error Message"] {
+                if let Some(msg) = obj.get(key).and_then(|v| v.as_str()) {
+                    return Err(FetchError::api(DataSource::AlphaVantage, response.status, &response.bytes, msg.to_string()));
+                }
+            }
+        }
     }
 
     let data = serde_json::from_slice::<AlphaVantageResponse>(&response.bytes)
-        .map_err(|e| anyhow::anyhow!("Alpha Vantage JSON parse error: {}", e))?;
+        .map_err(|e| FetchError::decode(DataSource::AlphaVantage, &response.bytes, format!("JSON parse error: {}", e)))?;
 
     let price = data.global_quote.price.parse::<f64>()
-        .map_err(|e| anyhow::anyhow!("Alpha Vantage price parse error: {}", e))?;
+        .map_err(|e| FetchError::decode(DataSource::AlphaVantage, &response.bytes, format!("price parse error: {}", e)))?;
 
     // Basic sanity check
     if price <= 0.0 || price > 1000000.0 {
-        return Err(anyhow::anyhow!("Alpha Vantage price out of reasonable range: {}", price));
+        return Err(FetchError::api(DataSource::AlphaVantage, response.status, &response.bytes, format!("price out of reasonable range: {}", price)));
     }
 
+    // GLOBAL_QUOTE doesn't report a session flag, so we can't distinguish
+    // pre/post-market from regular hours here -- assume Regular and let
+    // providers that do know better (TwelveData) drive session-aware logic.
     Ok(PriceResult {
         price,
         source: "Alpha Vantage".to_string(),
         confidence: 85, // Base confidence for Alpha Vantage
         timestamp: data.global_quote.trading_day.clone(),
-        metadata: Some(format!("Symbol: {}", data.global_quote.symbol)),
+        metadata: Some(format!("Symbol: {}, session=unknown(assumed regular)", data.global_quote.symbol)),
         error_info: None,
+        session: TradeSession::Regular,
+        currency: "USD".to_string(), // GLOBAL_QUOTE doesn't report a quote currency
     })
 }
 
-fn fetch_fmp_price(symbol: &str) -> Result<PriceResult> {
+/// Single-symbol FMP fetch using the default `OneDay` candle-cross-check
+/// period -- kept for the existing relaxed/batch call sites that don't carry
+/// a per-request period. `fetch_with_intelligent_retry` calls
+/// `fetch_fmp_price_for_period` instead, so the DR input's `@PERIOD`
+/// override actually reaches the candle cross-check.
+fn fetch_fmp_price(symbol: &str) -> Result<PriceResult, FetchError> {
+    fetch_fmp_price_for_period(symbol, CandlestickPeriod::OneDay)
+}
+
+fn fetch_fmp_price_for_period(symbol: &str, period: CandlestickPeriod) -> Result<PriceResult, FetchError> {
     let api_key = "q9fpsHHSXJJzhjB5GF6xFMiFbPc41c6m";
     let api_url = format!(
         "https://financialmodelingprep.com/api/v3/quote/{}?apikey={}",
@@ -1112,25 +1832,81 @@ fn fetch_fmp_price(symbol: &str) -> Result<PriceResult> {
     log!("🔍 FMP API call: {}", api_url);
 
     let response = http_fetch(api_url, None);
+    let retry_after = response.headers.iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+        .and_then(|(_, value)| value.parse::<u32>().ok());
 
     if !response.is_ok() {
-        return Err(anyhow::anyhow!("FMP HTTP error: {}", response.status));
+        return Err(FetchError::transport(DataSource::FinancialModelingPrep, response.status, &response.bytes, retry_after));
     }
 
     // FMP returns an array, so we need to parse it as Vec<FMPResponse>
     let data = serde_json::from_slice::<Vec<FMPResponse>>(&response.bytes)
-        .map_err(|e| anyhow::anyhow!("FMP JSON parse error: {}", e))?;
+        .map_err(|e| FetchError::decode(DataSource::FinancialModelingPrep, &response.bytes, format!("JSON parse error: {}", e)))?;
 
     if data.is_empty() {
-        return Err(anyhow::anyhow!("FMP returned empty response - symbol not found"));
+        return Err(FetchError::api(DataSource::FinancialModelingPrep, response.status, &response.bytes, "empty response - symbol not found".to_string()));
+    }
+
+    build_fmp_quote(symbol, &data[0], response.status, &response.bytes, period)
+}
+
+/// Fetches quotes for every symbol in `symbols` from FMP's bulk `/quote`
+/// endpoint (`/quote/sym1,sym2,...`) in a single HTTP round trip,
+/// instead of one request per symbol. Missing symbols (typos, delisted
+/// tickers) simply don't appear in FMP's response array and are reported
+/// as a not-found error for that symbol only -- the rest of the batch
+/// still resolves.
+fn fetch_fmp_quotes_batch(symbols: &[String]) -> HashMap<String, Result<PriceResult, FetchError>> {
+    let api_key = "q9fpsHHSXJJzhjB5GF6xFMiFbPc41c6m";
+    let joined = symbols.join(",");
+    let api_url = format!(
+        "https://financialmodelingprep.com/api/v3/quote/{}?apikey={}",
+        joined, api_key
+    );
+
+    log!("🔍 FMP batch API call ({} symbols): {}", symbols.len(), api_url);
+
+    let response = http_fetch(api_url, None);
+    let retry_after = response.headers.iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+        .and_then(|(_, value)| value.parse::<u32>().ok());
+
+    if !response.is_ok() {
+        let error = FetchError::transport(DataSource::FinancialModelingPrep, response.status, &response.bytes, retry_after);
+        return symbols.iter().map(|s| (s.clone(), Err(error.clone()))).collect();
     }
 
-    let quote = &data[0];
+    let data = match serde_json::from_slice::<Vec<FMPResponse>>(&response.bytes) {
+        Ok(data) => data,
+        Err(e) => {
+            let error = FetchError::decode(DataSource::FinancialModelingPrep, &response.bytes, format!("JSON parse error: {}", e));
+            return symbols.iter().map(|s| (s.clone(), Err(error.clone()))).collect();
+        }
+    };
+
+    symbols.iter()
+        .map(|symbol| {
+            let quote = data.iter().find(|q| q.symbol.eq_ignore_ascii_case(symbol));
+            let outcome = match quote {
+                Some(quote) => build_fmp_quote(symbol, quote, response.status, &response.bytes, CandlestickPeriod::OneDay),
+                None => Err(FetchError::api(DataSource::FinancialModelingPrep, response.status, &response.bytes, "symbol not found in batch response".to_string())),
+            };
+            (symbol.clone(), outcome)
+        })
+        .collect()
+}
+
+/// Builds a `PriceResult` from one FMP quote entry -- shared by the
+/// single-symbol `fetch_fmp_price` and the bulk `fetch_fmp_quotes_batch`,
+/// since FMP's `/quote` endpoint returns the identical per-symbol shape
+/// whether it was asked for one ticker or many.
+fn build_fmp_quote(symbol: &str, quote: &FMPResponse, response_status: u16, response_bytes: &[u8], period: CandlestickPeriod) -> Result<PriceResult, FetchError> {
     let price = quote.price;
 
     // Basic sanity check
     if price <= 0.0 || price > 1000000.0 {
-        return Err(anyhow::anyhow!("FMP price out of reasonable range: {}", price));
+        return Err(FetchError::api(DataSource::FinancialModelingPrep, response_status, response_bytes, format!("price out of reasonable range: {}", price)));
     }
 
     // Calculate confidence based on available metadata
@@ -1146,21 +1922,234 @@ fn fetch_fmp_price(symbol: &str) -> Result<PriceResult> {
         confidence = (confidence + 3).min(98);
     }
 
+    let mut metadata = format!(
+        "Exchange: {}, Volume: {}, Market Cap: {}",
+        quote.exchange.as_deref().unwrap_or("N/A"),
+        quote.volume.map(|v| v.to_string()).unwrap_or_else(|| "N/A".to_string()),
+        quote.market_cap.map(|mc| format!("{:.0}", mc)).unwrap_or_else(|| "N/A".to_string())
+    );
+
+    // Depth/spread, when FMP's plan reports it -- skipped gracefully
+    // otherwise, same as the SMA cross-check below.
+    if let (Some(bid), Some(ask)) = (quote.bid, quote.ask) {
+        let spread = relative_spread(bid, ask);
+        metadata.push_str(&format!(", bid={:.4}, ask={:.4}, spread={:.4}", bid, ask, spread));
+        confidence = adjust_confidence_for_spread(confidence, spread);
+        if is_price_outside_book(price, bid, ask) {
+            log!("⚠️ FMP spot price {} outside book [{:.4}, {:.4}]", price, bid, ask);
+            metadata.push_str(", price_outside_book=true");
+            confidence = confidence.saturating_sub(15);
+        }
+    }
+
+    // Cross-check the spot price against its own recent history: fetch daily
+    // bars, compute SMA-50/SMA-200 ourselves, compare against FMP's reported
+    // priceAvg50/priceAvg200, and make sure the quote isn't implausibly far
+    // outside the recent trading range. A quote that contradicts its own
+    // historical context gets down-weighted before it ever reaches
+    // `Process::success`.
+    match fetch_fmp_candles(symbol, period, true) {
+        Ok(candles) if candles.len() >= 10 => {
+            let closes: Vec<f64> = candles.iter().map(|c| c.close).collect();
+            let sma_50 = simple_moving_average(&closes, 50);
+            let sma_200 = simple_moving_average(&closes, 200);
+
+            if let (Some(computed_50), Some(reported_50)) = (sma_50, quote.price_avg_50) {
+                let agree = sma_agreement_ratio(computed_50, reported_50);
+                metadata.push_str(&format!(", sma50_computed={:.4}, sma50_reported={:.4}, sma50_agreement={:.4}", computed_50, reported_50, agree));
+                confidence = adjust_confidence_for_sma_agreement(confidence, agree);
+            }
+            if let (Some(computed_200), Some(reported_200)) = (sma_200, quote.price_avg_200) {
+                let agree = sma_agreement_ratio(computed_200, reported_200);
+                metadata.push_str(&format!(", sma200_computed={:.4}, sma200_reported={:.4}, sma200_agreement={:.4}", computed_200, reported_200, agree));
+                confidence = adjust_confidence_for_sma_agreement(confidence, agree);
+            }
+
+            if let Some((low, high)) = recent_bar_range(&candles, 20) {
+                if is_price_outside_recent_range(price, low, high) {
+                    log!("⚠️ FMP spot price {} is implausible against 20-bar range [{:.4}, {:.4}]", price, low, high);
+                    metadata.push_str(&format!(", stale_vs_range=true range=[{:.4},{:.4}]", low, high));
+                    confidence = confidence.saturating_sub(20);
+                } else {
+                    metadata.push_str(", stale_vs_range=false");
+                }
+            }
+        }
+        Ok(_) => {
+            log!("⚠️ FMP historical candles too short for SMA cross-check on {}", symbol);
+        }
+        Err(e) => {
+            log!("⚠️ FMP historical candle fetch failed for {}: {}", symbol, e);
+        }
+    }
+
+    // FMP's quote endpoint doesn't report a session flag either, so fall
+    // back to the same UTC-hour heuristic TwelveData uses when closed.
+    let session = quote.timestamp
+        .map(|t| resolve_twelve_data_session(false, t))
+        .unwrap_or(TradeSession::Regular);
+    confidence = adjust_confidence_for_session(confidence, session);
+    metadata.push_str(&format!(", session={}", session.as_str()));
+
     Ok(PriceResult {
         price,
         source: "Financial Modeling Prep".to_string(),
         confidence,
         timestamp: quote.timestamp.map(|t| t.to_string()).unwrap_or_else(|| "N/A".to_string()),
-        metadata: Some(format!(
-            "Exchange: {}, Volume: {}, Market Cap: {}",
-            quote.exchange.as_deref().unwrap_or("N/A"),
-            quote.volume.map(|v| v.to_string()).unwrap_or_else(|| "N/A".to_string()),
-            quote.market_cap.map(|mc| format!("{:.0}", mc)).unwrap_or_else(|| "N/A".to_string())
-        )),
+        metadata: Some(metadata),
         error_info: None,
+        session,
+        currency: "USD".to_string(), // FMP's /quote endpoint doesn't report a quote currency
     })
 }
 
+/// Fetches a bar series from FMP's historical-chart endpoints. Intraday
+/// periods (1min/5min/1hour) come back as a flat array; the daily period
+/// comes back wrapped in `{"symbol": ..., "historical": [...]}`.
+/// `adjusted` selects the split/dividend-adjusted close for daily bars.
+fn fetch_fmp_candles(symbol: &str, period: CandlestickPeriod, adjusted: bool) -> Result<Vec<Candle>, FetchError> {
+    let api_key = "q9fpsHHSXJJzhjB5GF6xFMiFbPc41c6m";
+
+    let api_url = match period {
+        CandlestickPeriod::OneDay => format!(
+            "https://financialmodelingprep.com/api/v3/historical-price-full/{}?serietype=line&apikey={}",
+            symbol, api_key
+        ),
+        other => format!(
+            "https://financialmodelingprep.com/api/v3/historical-chart/{}/{}?apikey={}",
+            other.fmp_path_segment(), symbol, api_key
+        ),
+    };
+
+    log!("🔍 FMP historical candles call: {}", api_url);
+
+    let response = http_fetch(api_url, None);
+    let retry_after = response.headers.iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+        .and_then(|(_, value)| value.parse::<u32>().ok());
+
+    if !response.is_ok() {
+        return Err(FetchError::transport(DataSource::FinancialModelingPrep, response.status, &response.bytes, retry_after));
+    }
+
+    match period {
+        CandlestickPeriod::OneDay => {
+            let data = serde_json::from_slice::<FMPDailyHistoryResponse>(&response.bytes)
+                .map_err(|e| FetchError::decode(DataSource::FinancialModelingPrep, &response.bytes, format!("daily history JSON parse error: {}", e)))?;
+
+            Ok(data.historical.into_iter()
+                .map(|bar| Candle {
+                    open: bar.open,
+                    high: bar.high,
+                    low: bar.low,
+                    close: if adjusted { bar.adj_close.unwrap_or(bar.close) } else { bar.close },
+                    volume: bar.volume,
+                    timestamp: bar.date,
+                })
+                // FMP returns newest-first; chronological order matches the
+                // rest of the series-processing code (SMA, range checks).
+                .rev()
+                .collect())
+        }
+        _ => {
+            let data = serde_json::from_slice::<Vec<FMPIntradayBar>>(&response.bytes)
+                .map_err(|e| FetchError::decode(DataSource::FinancialModelingPrep, &response.bytes, format!("intraday history JSON parse error: {}", e)))?;
+
+            Ok(data.into_iter()
+                .map(|bar| Candle {
+                    open: bar.open,
+                    high: bar.high,
+                    low: bar.low,
+                    close: bar.close,
+                    volume: bar.volume,
+                    timestamp: bar.date,
+                })
+                .rev()
+                .collect())
+        }
+    }
+}
+
+/// Arithmetic SMA over the trailing `window` closes. `None` if there isn't
+/// enough history yet.
+fn simple_moving_average(closes: &[f64], window: usize) -> Option<f64> {
+    if closes.len() < window {
+        return None;
+    }
+    let trailing = &closes[closes.len() - window..];
+    Some(trailing.iter().sum::<f64>() / window as f64)
+}
+
+/// 1.0 for a perfect match, decaying towards 0.0 as the computed and
+/// provider-reported averages diverge (relative to the reported value).
+fn sma_agreement_ratio(computed: f64, reported: f64) -> f64 {
+    if reported == 0.0 {
+        return 0.0;
+    }
+    let relative_diff = ((computed - reported).abs() / reported.abs()).min(1.0);
+    1.0 - relative_diff
+}
+
+/// Down-weights confidence when our own SMA disagrees with the provider's
+/// reported average; a solid agreement leaves confidence untouched.
+fn adjust_confidence_for_sma_agreement(confidence: u8, agreement: f64) -> u8 {
+    if agreement >= 0.98 {
+        confidence
+    } else {
+        let penalty = ((1.0 - agreement) * 30.0) as u8;
+        confidence.saturating_sub(penalty)
+    }
+}
+
+fn recent_bar_range(candles: &[Candle], bars: usize) -> Option<(f64, f64)> {
+    if candles.is_empty() {
+        return None;
+    }
+    let start = candles.len().saturating_sub(bars);
+    let recent = &candles[start..];
+    let low = recent.iter().map(|c| c.low).fold(f64::INFINITY, f64::min);
+    let high = recent.iter().map(|c| c.high).fold(f64::NEG_INFINITY, f64::max);
+    Some((low, high))
+}
+
+/// A spot price is rejected as stale/implausible when it falls far outside
+/// the recent bar range -- beyond a 5% cushion on either side.
+fn is_price_outside_recent_range(price: f64, low: f64, high: f64) -> bool {
+    let cushion = (high - low).max(high * 0.01) * 0.05;
+    price < low - cushion || price > high + cushion
+}
+
+/// Relative bid/ask spread, `(ask - bid) / mid`. A thin or dislocated market
+/// can carry a wide spread even while every feed agrees on the last price,
+/// which cross-source agreement alone can't catch.
+fn relative_spread(bid: f64, ask: f64) -> f64 {
+    let mid = (bid + ask) / 2.0;
+    if mid <= 0.0 {
+        return 0.0;
+    }
+    (ask - bid) / mid
+}
+
+/// A last price reported outside the current top-of-book is a sign the quote
+/// and the book are out of sync.
+fn is_price_outside_book(price: f64, bid: f64, ask: f64) -> bool {
+    price < bid || price > ask
+}
+
+/// Penalizes confidence for a pathologically wide relative spread. A tight
+/// market (well under 1%) isn't adjusted at all.
+fn adjust_confidence_for_spread(confidence: u8, spread: f64) -> u8 {
+    if spread > 0.05 {
+        confidence.saturating_sub(20)
+    } else if spread > 0.02 {
+        confidence.saturating_sub(10)
+    } else if spread > 0.01 {
+        confidence.saturating_sub(5)
+    } else {
+        confidence
+    }
+}
+
 fn cross_validate_prices_robust(av_result: PriceResult, fmp_result: PriceResult) -> PriceResult {
     let price_diff = (av_result.price - fmp_result.price).abs();
     let price_diff_percent = (price_diff / av_result.price.max(fmp_result.price)) * 100.0;
@@ -1182,16 +2171,19 @@ fn cross_validate_prices_robust(av_result: PriceResult, fmp_result: PriceResult)
         let weight_av = av_result.confidence as f64 / 100.0;
         let weight_fmp = fmp_result.confidence as f64 / 100.0;
         let total_weight = weight_av + weight_fmp;
-        
+
         let weighted_price = (av_result.price * weight_av + fmp_result.price * weight_fmp) / total_weight;
-        
+        let session = combined_session(&[av_result.session, fmp_result.session]);
+
         PriceResult {
             price: weighted_price,
             source: "Weighted Cross-validation".to_string(),
             confidence: 80,
             timestamp: format!("AV: {} | FMP: {}", av_result.timestamp, fmp_result.timestamp),
-            metadata: Some(format!("⚠️ {:.2}% difference, weighted average", price_diff_percent)),
+            metadata: Some(format!("⚠️ {:.2}% difference, weighted average, session={}", price_diff_percent, session.as_str())),
             error_info: None,
+            session,
+            currency: av_result.currency.clone(),
         }
     } else if price_diff_percent <= 15.0 {
         log!("🚨 Large disagreement ({:.2}%) - using higher confidence source", price_diff_percent);
@@ -1202,15 +2194,21 @@ fn cross_validate_prices_robust(av_result: PriceResult, fmp_result: PriceResult)
         result
     } else {
         log!("🆘 Extreme disagreement ({:.2}%) - emergency protocol", price_diff_percent);
-        // Return average but with very low confidence
-        let average_price = (av_result.price + fmp_result.price) / 2.0;
+        // Return the median but with very low confidence. With only two
+        // points this is the same as the plain average, but it keeps the
+        // consensus price computed the same robust way as the three-source
+        // path above rather than a separate ad-hoc mean.
+        let average_price = median_of(&[av_result.price, fmp_result.price]);
+        let session = combined_session(&[av_result.session, fmp_result.session]);
         PriceResult {
             price: average_price,
             source: "Emergency Average (Extreme disagreement)".to_string(),
             confidence: 40,
             timestamp: format!("AV: {} | FMP: {}", av_result.timestamp, fmp_result.timestamp),
-            metadata: Some(format!("🆘 Extreme disagreement: {:.2}%", price_diff_percent)),
+            metadata: Some(format!("🆘 Extreme disagreement: {:.2}%, session={}", price_diff_percent, session.as_str())),
             error_info: Some(format!("Extreme price disagreement: {:.2}%", price_diff_percent)),
+            session,
+            currency: av_result.currency.clone(),
         }
     }
 }
@@ -1222,7 +2220,7 @@ fn enhance_single_source_result(mut result: PriceResult, additional_info: String
     result
 }
 
-fn fetch_alpha_vantage_price_relaxed(symbol: &str) -> Result<PriceResult> {
+fn fetch_alpha_vantage_price_relaxed(symbol: &str) -> Result<PriceResult, FetchError> {
     // Relaxed version with more lenient validation
     match fetch_alpha_vantage_price(symbol) {
         Ok(result) => Ok(result),
@@ -1234,7 +2232,7 @@ fn fetch_alpha_vantage_price_relaxed(symbol: &str) -> Result<PriceResult> {
     }
 }
 
-fn fetch_fmp_price_relaxed(symbol: &str) -> Result<PriceResult> {
+fn fetch_fmp_price_relaxed(symbol: &str) -> Result<PriceResult, FetchError> {
     // Relaxed version with more lenient validation
     match fetch_fmp_price(symbol) {
         Ok(result) => Ok(result),
@@ -1246,27 +2244,495 @@ fn fetch_fmp_price_relaxed(symbol: &str) -> Result<PriceResult> {
     }
 }
 
-fn generate_alternative_symbols(symbol: &str) -> Vec<String> {
-    let mut alternatives = Vec::new();
-    
-    // Add common variations
-    if symbol.len() <= 4 {
-        // Try with different suffixes for short symbols
-        alternatives.push(format!("{}.US", symbol));
-        alternatives.push(format!("{}.O", symbol));
+/// A price-quote source that can be fetched by symbol. Each existing
+/// provider implements this so new sources can be added without touching
+/// the reconciliation logic below, and callers can assemble whatever
+/// provider set fits the situation instead of hardcoding per-provider calls.
+trait QuotesProvider {
+    fn source(&self) -> DataSource;
+    fn fetch(&self, symbol: &str) -> Result<PriceResult, FetchError>;
+
+    /// Resolves every symbol in `symbols` against this provider. The default
+    /// falls back to one `fetch` call per symbol for providers with no
+    /// documented bulk endpoint; providers that do expose one (e.g. FMP's
+    /// `/quote/sym1,sym2,...`) override this to resolve the whole batch in a
+    /// single HTTP round trip instead.
+    fn fetch_batch(&self, symbols: &[String]) -> HashMap<String, Result<PriceResult, FetchError>> {
+        symbols.iter().map(|s| (s.clone(), self.fetch(s))).collect()
     }
-    
-    // Try reverse fuzzy matching
+}
+
+struct AlphaVantageQuotes;
+impl QuotesProvider for AlphaVantageQuotes {
+    fn source(&self) -> DataSource {
+        DataSource::AlphaVantage
+    }
+    fn fetch(&self, symbol: &str) -> Result<PriceResult, FetchError> {
+        fetch_alpha_vantage_price_relaxed(symbol)
+    }
+}
+
+struct FmpQuotes;
+impl QuotesProvider for FmpQuotes {
+    fn source(&self) -> DataSource {
+        DataSource::FinancialModelingPrep
+    }
+    fn fetch(&self, symbol: &str) -> Result<PriceResult, FetchError> {
+        fetch_fmp_price_relaxed(symbol)
+    }
+    fn fetch_batch(&self, symbols: &[String]) -> HashMap<String, Result<PriceResult, FetchError>> {
+        fetch_fmp_quotes_batch(symbols)
+    }
+}
+
+struct TwelveDataQuotes;
+impl QuotesProvider for TwelveDataQuotes {
+    fn source(&self) -> DataSource {
+        DataSource::TwelveData
+    }
+    fn fetch(&self, symbol: &str) -> Result<PriceResult, FetchError> {
+        fetch_twelve_data_price_relaxed(symbol)
+    }
+}
+
+/// Caches a fetched quote per (provider, symbol) for `ttl_ms`, so multiple
+/// strategies probing the same symbol within one execution (e.g. an
+/// alternative-symbol retry that lands back on the original ticker) don't
+/// re-hit rate-limited provider APIs. Scoped to a single execution_phase
+/// run -- there's no persistent storage between oracle invocations -- but
+/// that's still the window repeated lookups happen in.
+struct QuoteCache {
+    ttl_ms: u128,
+    entries: HashMap<String, (PriceResult, std::time::SystemTime)>,
+}
+
+impl QuoteCache {
+    fn new(ttl_ms: u128) -> Self {
+        QuoteCache { ttl_ms, entries: HashMap::new() }
+    }
+
+    fn get(&self, key: &str) -> Option<PriceResult> {
+        self.entries.get(key).and_then(|(result, cached_at)| {
+            match cached_at.elapsed() {
+                Ok(age) if age.as_millis() <= self.ttl_ms => Some(result.clone()),
+                _ => None,
+            }
+        })
+    }
+
+    fn put(&mut self, key: &str, result: PriceResult) {
+        self.entries.insert(key.to_string(), (result, std::time::SystemTime::now()));
+    }
+}
+
+/// How far a provider's price may deviate from the consensus median before
+/// `consensus_quote` excludes it as an outlier instead of folding it in.
+const QUOTE_OUTLIER_DEVIATION_PCT: f64 = 0.03;
+
+/// Queries every provider in `providers` for `symbol` (serving cached
+/// quotes from `cache` where still fresh), and reconciles the successful
+/// responses into one quote: the median price is the consensus value, and
+/// any provider whose price deviates from it by more than
+/// `QUOTE_OUTLIER_DEVIATION_PCT` is named as an outlier in `metadata`
+/// rather than silently blended in. Returns `None` only if every provider
+/// failed.
+fn consensus_quote(providers: &[&dyn QuotesProvider], symbol: &str, cache: &mut QuoteCache) -> Option<PriceResult> {
+    let mut quotes: Vec<PriceResult> = Vec::new();
+
+    for provider in providers {
+        let cache_key = format!("{:?}:{}", provider.source(), symbol);
+        if let Some(cached) = cache.get(&cache_key) {
+            log!("💾 Quote cache hit for {:?}: {}", provider.source(), symbol);
+            quotes.push(cached);
+            continue;
+        }
+
+        match provider.fetch(symbol) {
+            Ok(quote) => {
+                cache.put(&cache_key, quote.clone());
+                quotes.push(quote);
+            }
+            Err(error) => {
+                log!("⚠️ Quotes provider {:?} failed for {}: {}", provider.source(), symbol, error);
+            }
+        }
+    }
+
+    if quotes.is_empty() {
+        return None;
+    }
+
+    let prices: Vec<f64> = quotes.iter().map(|q| q.price).collect();
+    let median = median_of(&prices);
+
+    let mut sources_used = Vec::new();
+    let mut outliers = Vec::new();
+    for quote in &quotes {
+        let deviation = if median.abs() > f64::EPSILON {
+            (quote.price - median).abs() / median.abs()
+        } else {
+            0.0
+        };
+        if deviation > QUOTE_OUTLIER_DEVIATION_PCT {
+            outliers.push(format!("{} (${:.4}, {:.1}% from consensus)", quote.source, quote.price, deviation * 100.0));
+        } else {
+            sources_used.push(quote.source.clone());
+        }
+    }
+
+    let mut consensus = quotes[0].clone();
+    consensus.price = median;
+    consensus.source = format!("Quotes consensus [{}]", sources_used.join(", "));
+    consensus.metadata = if outliers.is_empty() {
+        Some(format!("{} providers agreed", sources_used.len()))
+    } else {
+        Some(format!("outliers excluded from consensus: {}", outliers.join("; ")))
+    };
+    let avg_confidence = quotes.iter().map(|q| q.confidence as u32).sum::<u32>() / quotes.len() as u32;
+    consensus.confidence = (avg_confidence as u8).saturating_sub(if outliers.is_empty() { 0 } else { 5 });
+
+    Some(consensus)
+}
+
+/// The outcome of one symbol's lookup within a `fetch_many` batch: the
+/// resolved consensus quote (if any provider answered), and which
+/// providers actually satisfied it versus came back empty -- so a caller
+/// can tell "no data anywhere" apart from "one provider was down but the
+/// rest covered it".
+struct BatchQuoteOutcome {
+    quote: Option<PriceResult>,
+    providers_used: Vec<DataSource>,
+    providers_failed: Vec<DataSource>,
+}
+
+/// Resolves quotes for every symbol in `symbols` against the full Quotes
+/// provider set, sharing one `QuoteCache` across the whole batch so a
+/// portfolio with repeated tickers only pays for each (provider, symbol)
+/// pair once. Providers are queried outer-loop (one `fetch_batch` call per
+/// provider covering every cache-miss symbol at once) rather than
+/// symbol-by-symbol -- for FMP that's a single bulk `/quote/sym1,sym2,...`
+/// request instead of N serial ones; TwelveData and Alpha Vantage have no
+/// documented bulk-symbol endpoint, so their `fetch_batch` still falls back
+/// to one `fetch` call per symbol. This still isn't true concurrent
+/// dispatch -- the oracle's execution VM has no thread pool to run real
+/// parallel requests on -- but it does cut the provider round trips for a
+/// portfolio from O(symbols) to O(1) per provider where a bulk endpoint
+/// exists. A provider outage never aborts the batch -- it's recorded in
+/// each affected symbol's `providers_failed` and the rest keeps going.
+fn fetch_many(symbols: &[String]) -> HashMap<String, BatchQuoteOutcome> {
+    let providers: Vec<&dyn QuotesProvider> = vec![&TwelveDataQuotes, &AlphaVantageQuotes, &FmpQuotes];
+    let mut cache = QuoteCache::new(60_000);
+
+    let mut providers_used: HashMap<String, Vec<DataSource>> =
+        symbols.iter().map(|s| (s.clone(), Vec::new())).collect();
+    let mut providers_failed: HashMap<String, Vec<DataSource>> =
+        symbols.iter().map(|s| (s.clone(), Vec::new())).collect();
+    let mut quotes_by_symbol: HashMap<String, Vec<PriceResult>> =
+        symbols.iter().map(|s| (s.clone(), Vec::new())).collect();
+
+    for provider in &providers {
+        let mut misses: Vec<String> = Vec::new();
+        for symbol in symbols {
+            let cache_key = format!("{:?}:{}", provider.source(), symbol);
+            if let Some(cached) = cache.get(&cache_key) {
+                providers_used.get_mut(symbol).unwrap().push(provider.source());
+                quotes_by_symbol.get_mut(symbol).unwrap().push(cached);
+            } else {
+                misses.push(symbol.clone());
+            }
+        }
+
+        if misses.is_empty() {
+            continue;
+        }
+
+        for (symbol, outcome) in provider.fetch_batch(&misses) {
+            match outcome {
+                Ok(quote) => {
+                    let cache_key = format!("{:?}:{}", provider.source(), symbol);
+                    cache.put(&cache_key, quote.clone());
+                    providers_used.get_mut(&symbol).unwrap().push(provider.source());
+                    quotes_by_symbol.get_mut(&symbol).unwrap().push(quote);
+                }
+                Err(error) => {
+                    log!("⚠️ fetch_many: {:?} failed for {}: {}", provider.source(), symbol, error);
+                    providers_failed.get_mut(&symbol).unwrap().push(provider.source());
+                }
+            }
+        }
+    }
+
+    let mut results = HashMap::new();
+    for symbol in symbols {
+        let quotes = quotes_by_symbol.remove(symbol).unwrap_or_default();
+        let used = providers_used.remove(symbol).unwrap_or_default();
+        let failed = providers_failed.remove(symbol).unwrap_or_default();
+
+        let quote = if quotes.is_empty() {
+            None
+        } else {
+            let prices: Vec<f64> = quotes.iter().map(|q| q.price).collect();
+            let median = median_of(&prices);
+            let mut consensus = quotes[0].clone();
+            consensus.price = median;
+            consensus.source = format!(
+                "Quotes consensus [{}]",
+                used.iter().map(|s| format!("{:?}", s)).collect::<Vec<_>>().join(", ")
+            );
+            Some(consensus)
+        };
+
+        results.insert(symbol.clone(), BatchQuoteOutcome { quote, providers_used: used, providers_failed: failed });
+    }
+
+    results
+}
+
+/// Known tradable symbols the fuzzy matcher ranks candidates from. Pulled
+/// from the same universe `create_fuzzy_symbol_map`/`create_reverse_fuzzy_map`
+/// already name, plus a handful of other large, frequently-mistyped names.
+const KNOWN_SYMBOLS: &[&str] = &[
+    "AAPL", "MSFT", "GOOGL", "GOOG", "AMZN", "TSLA", "META", "NVDA", "NFLX",
+    "SPY", "QQQ", "DIA", "IWM", "BRK.A", "BRK.B", "JPM", "V", "MA", "DIS", "BA",
+];
+
+/// Score of a single `query` character matching at candidate index `ci`,
+/// using fzf's own per-character scoring weights: a flat per-character
+/// match score, a bonus for landing on a word boundary (start-of-string or
+/// right after a non-alphanumeric separator), a bonus that grows with each
+/// further consecutive matched character, a penalty proportional to
+/// candidate characters skipped since the last match, and a small penalty
+/// when the match only succeeded by ignoring case.
+fn fuzzy_char_score(is_boundary: bool, consecutive_run: i32, gap: i32, case_mismatch: bool) -> i32 {
+    const SCORE_MATCH: i32 = 16;
+    const BONUS_BOUNDARY: i32 = 8;
+    const BONUS_CONSECUTIVE: i32 = 4;
+    const PENALTY_GAP: i32 = 1;
+    const PENALTY_CASE_MISMATCH: i32 = 1;
+
+    let mut score = SCORE_MATCH;
+    if is_boundary {
+        score += BONUS_BOUNDARY;
+    }
+    score += BONUS_CONSECUTIVE * consecutive_run;
+    score -= PENALTY_GAP * gap;
+    if case_mismatch {
+        score -= PENALTY_CASE_MISMATCH;
+    }
+    score
+}
+
+/// fzf-style fuzzy match: scans `candidate` left-to-right, greedily
+/// matching each `query` character against the first remaining candidate
+/// character that matches it, scoring each match with `fuzzy_char_score`.
+/// Returns `None` when the query's characters don't all appear in order in
+/// the candidate; otherwise the greedy alignment's score (higher is a
+/// better match) and its matched candidate indices.
+///
+/// This is a greedy approximation, not fzf's real matching recurrence (a DP
+/// over every valid alignment to find the globally optimal one). Committing
+/// to the first in-order match for each query character means a later,
+/// better-scoring alignment -- e.g. one landing on a word boundary a few
+/// characters further along -- is never reconsidered, so this can
+/// occasionally score (and therefore rank) a candidate lower than real fzf
+/// would. Good enough for ranking short tickers/company names against a
+/// small known-symbol universe, where alignments rarely have more than one
+/// plausible match position to begin with.
+fn fuzzy_match_score(query: &str, candidate: &str) -> Option<(i32, Vec<usize>)> {
+    let query_chars: Vec<char> = query.chars().collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+
+    if query_chars.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    let mut qi = 0usize;
+    let mut score = 0i32;
+    let mut consecutive_run = 0i32;
+    let mut last_matched_index: Option<usize> = None;
+    let mut matched_indices = Vec::with_capacity(query_chars.len());
+
+    for (ci, &c) in candidate_chars.iter().enumerate() {
+        if qi >= query_chars.len() {
+            break;
+        }
+
+        let q = query_chars[qi];
+        if c.to_ascii_lowercase() != q.to_ascii_lowercase() {
+            continue;
+        }
+
+        let is_boundary = ci == 0 || !candidate_chars[ci - 1].is_alphanumeric();
+        let gap = match last_matched_index {
+            Some(last) if ci == last + 1 => {
+                consecutive_run += 1;
+                0
+            }
+            Some(last) => {
+                consecutive_run = 0;
+                (ci - last - 1) as i32
+            }
+            None => {
+                consecutive_run = 0;
+                0
+            }
+        };
+
+        score += fuzzy_char_score(is_boundary, consecutive_run, gap, c != q);
+        last_matched_index = Some(ci);
+        matched_indices.push(ci);
+        qi += 1;
+    }
+
+    if qi < query_chars.len() {
+        return None;
+    }
+
+    Some((score, matched_indices))
+}
+
+/// The match type a single query atom applies, modeled on fuzzy-finder query
+/// syntax: a leading `'` anchors a contiguous substring, `^`/`$` anchor a
+/// prefix/suffix, and bare text is fuzzy (fzf-style, scored).
+#[derive(Debug, Clone)]
+enum AtomMatcher {
+    Substring(String),
+    Prefix(String),
+    Suffix(String),
+    Fuzzy(String),
+}
+
+/// One atom of a query-atom search string, e.g. the `^SP` or `!3x` in
+/// `"^SP !3x"`. A leading `!` inverts the underlying matcher -- the
+/// candidate must NOT match for the atom to pass. A whitespace-separated
+/// query combines its atoms with AND semantics: every atom must pass for a
+/// candidate to qualify.
+#[derive(Debug, Clone)]
+struct QueryAtom {
+    matcher: AtomMatcher,
+    invert: bool,
+}
+
+impl QueryAtom {
+    fn parse(raw: &str) -> Self {
+        let (invert, rest) = match raw.strip_prefix('!') {
+            Some(stripped) => (true, stripped),
+            None => (false, raw),
+        };
+
+        let matcher = if let Some(text) = rest.strip_prefix('\'') {
+            AtomMatcher::Substring(text.to_string())
+        } else if let Some(text) = rest.strip_prefix('^') {
+            AtomMatcher::Prefix(text.to_string())
+        } else if let Some(text) = rest.strip_prefix('$') {
+            AtomMatcher::Suffix(text.to_string())
+        } else {
+            AtomMatcher::Fuzzy(rest.to_string())
+        };
+
+        QueryAtom { matcher, invert }
+    }
+
+    /// Evaluates this atom against `candidate`: whether it passes, and its
+    /// score contribution (anchored/inverted atoms just gate membership and
+    /// contribute no score; only a non-inverted fuzzy atom scores).
+    fn evaluate(&self, candidate: &str) -> (bool, i32) {
+        let candidate_lower = candidate.to_lowercase();
+
+        let (matched, score) = match &self.matcher {
+            AtomMatcher::Substring(text) => (candidate_lower.contains(&text.to_lowercase()), 0),
+            AtomMatcher::Prefix(text) => (candidate_lower.starts_with(&text.to_lowercase()), 0),
+            AtomMatcher::Suffix(text) => (candidate_lower.ends_with(&text.to_lowercase()), 0),
+            AtomMatcher::Fuzzy(text) => match fuzzy_match_score(text, candidate) {
+                Some((score, _)) => (true, score),
+                None => (false, 0),
+            },
+        };
+
+        if self.invert {
+            (!matched, 0)
+        } else {
+            (matched, score)
+        }
+    }
+
+    /// Minimum score a passing fuzzy atom could contribute, used to size
+    /// the overall match threshold. Zero for non-fuzzy or inverted atoms,
+    /// which don't score.
+    fn score_floor(&self) -> i32 {
+        match (&self.matcher, self.invert) {
+            (AtomMatcher::Fuzzy(text), false) => text.chars().count() as i32 * 8,
+            _ => 0,
+        }
+    }
+}
+
+/// Parses a whitespace-separated query-atom string, e.g. `"^SP !3x"`, into
+/// its atoms.
+fn parse_query_atoms(query: &str) -> Vec<QueryAtom> {
+    query.split_whitespace().map(QueryAtom::parse).collect()
+}
+
+/// Scores `candidate` against every parsed atom (AND semantics): all atoms
+/// must pass, and the combined score is the sum of each atom's contribution.
+fn score_candidate_against_atoms(atoms: &[QueryAtom], candidate: &str) -> Option<i32> {
+    let mut total = 0;
+    for atom in atoms {
+        let (matched, score) = atom.evaluate(candidate);
+        if !matched {
+            return None;
+        }
+        total += score;
+    }
+    Some(total)
+}
+
+/// Ranks `KNOWN_SYMBOLS` against a query that may be a bare (possibly
+/// mistyped/partial) ticker like "APPL"/"MICROSFT"/"tesla", or a full
+/// query-atom expression like `"^SP !3x"`, and returns the top `limit`
+/// candidates that clear the score threshold.
+fn best_fuzzy_symbol_matches(query: &str, limit: usize) -> Vec<String> {
+    let atoms = parse_query_atoms(query);
+    if atoms.is_empty() {
+        return Vec::new();
+    }
+    let threshold: i32 = atoms.iter().map(QueryAtom::score_floor).sum();
+
+    let mut scored: Vec<(i32, &str)> = KNOWN_SYMBOLS.iter()
+        .filter_map(|&candidate| score_candidate_against_atoms(&atoms, candidate).map(|score| (score, candidate)))
+        .filter(|&(score, _)| score >= threshold)
+        .collect();
+
+    scored.sort_by(|a, b| b.0.cmp(&a.0));
+    scored.into_iter().take(limit).map(|(_, candidate)| candidate.to_string()).collect()
+}
+
+fn generate_alternative_symbols(symbol: &str) -> Vec<String> {
+    // Rank known symbols by fzf-style fuzzy score instead of the old fixed
+    // `.US`/`.O`/trailing-A heuristics -- this catches mistyped or partial
+    // tickers ("APPL", "MICROSFT") that those heuristics never covered.
+    let mut alternatives = best_fuzzy_symbol_matches(symbol, 3);
+
+    // Still worth trying the reverse company-name mapping alongside the
+    // scored ticker candidates.
     let fuzzy_map = create_reverse_fuzzy_map();
     if let Some(alt) = fuzzy_map.get(symbol) {
-        alternatives.push(alt.clone());
+        if !alternatives.contains(alt) {
+            alternatives.push(alt.clone());
+        }
     }
-    
-    // Try common symbol transformations
-    if symbol.ends_with('A') && symbol.len() > 1 {
-        alternatives.push(symbol[..symbol.len()-1].to_string()); // Remove trailing A
+
+    // If the symbol looks like an unseparated crypto pair (e.g. "BTCUSD")
+    // that slipped past validation already separated, suggest the
+    // canonical BASE-QUOTE form -- some providers reject the concatenated
+    // form outright.
+    if let Some(pair) = parse_crypto_pair(symbol, DEFAULT_QUOTE_CURRENCIES) {
+        let canonical = pair.canonical();
+        if canonical != symbol.to_uppercase() && !alternatives.contains(&canonical) {
+            alternatives.push(canonical);
+        }
     }
-    
+
     alternatives
 }
 
@@ -1281,6 +2747,106 @@ fn create_reverse_fuzzy_map() -> HashMap<String, String> {
     // ETF alternatives
     map.insert("SPY".to_string(), "SPDR".to_string());
     map.insert("QQQ".to_string(), "NASDAQ".to_string());
-    
+
     map
 }
+
+#[cfg(test)]
+mod parse_crypto_pair_tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_explicit_separators() {
+        for input in ["BTC-USD", "BTC_USD", "btc-usd"] {
+            let pair = parse_crypto_pair(input, DEFAULT_QUOTE_CURRENCIES).expect("should parse");
+            assert_eq!(pair.base, "BTC");
+            assert_eq!(pair.quote, "USD");
+        }
+    }
+
+    #[test]
+    fn recognizes_bare_suffix_pairs() {
+        let pair = parse_crypto_pair("BTCUSDT", DEFAULT_QUOTE_CURRENCIES).expect("should parse");
+        assert_eq!(pair.base, "BTC");
+        assert_eq!(pair.quote, "USDT");
+    }
+
+    #[test]
+    fn bare_suffix_prefers_longest_quote_match() {
+        // "USDT" should win over the "USD" it contains.
+        let pair = parse_crypto_pair("ETHUSDT", DEFAULT_QUOTE_CURRENCIES).expect("should parse");
+        assert_eq!(pair.base, "ETH");
+        assert_eq!(pair.quote, "USDT");
+    }
+
+    #[test]
+    fn rejects_bare_suffix_equity_false_positives() {
+        // "GBTC" ends with "BTC" but is a known equity ETF, and its leftover
+        // base ("G") is shorter than MIN_CRYPTO_BASE_LEN either way.
+        assert!(parse_crypto_pair("GBTC", DEFAULT_QUOTE_CURRENCIES).is_none());
+    }
+
+    #[test]
+    fn rejects_known_equity_tickers_outright() {
+        for symbol in ["AAPL", "TSLA", "SPY"] {
+            assert!(parse_crypto_pair(symbol, DEFAULT_QUOTE_CURRENCIES).is_none());
+        }
+    }
+
+    // Regression for the chunk2-5 ordering bug: a '/'-separated pair whose
+    // quote is a recognized currency code must still parse as crypto, even
+    // though `extract_target_currency_override` also recognizes that same
+    // slash syntax.
+    #[test]
+    fn recognizes_slash_pairs_with_currency_code_quotes() {
+        let btc_usd = parse_crypto_pair("BTC/USD", DEFAULT_QUOTE_CURRENCIES).expect("should parse");
+        assert_eq!(btc_usd.base, "BTC");
+        assert_eq!(btc_usd.quote, "USD");
+
+        let eth_btc = parse_crypto_pair("ETH/BTC", DEFAULT_QUOTE_CURRENCIES).expect("should parse");
+        assert_eq!(eth_btc.base, "ETH");
+        assert_eq!(eth_btc.quote, "BTC");
+    }
+
+    // A known equity ticker plus a currency-override suffix must NOT be
+    // mistaken for a crypto pair just because the quote matches a
+    // recognized currency code.
+    #[test]
+    fn does_not_misparse_equity_currency_override_as_crypto() {
+        assert!(parse_crypto_pair("AAPL/EUR", DEFAULT_QUOTE_CURRENCIES).is_none());
+    }
+
+    // Once a separator is present but didn't produce a match (e.g. the
+    // "AAPL/EUR" case above), parsing must not fall through to the
+    // bare-suffix heuristic using the unstripped string.
+    #[test]
+    fn does_not_fall_through_to_bare_suffix_after_rejected_separator() {
+        assert!(parse_crypto_pair("AAPL/EUR", DEFAULT_QUOTE_CURRENCIES).is_none());
+        assert!(parse_crypto_pair("MSFT/GBP", DEFAULT_QUOTE_CURRENCIES).is_none());
+    }
+
+    #[test]
+    fn extract_target_currency_override_preserves_crypto_pairs() {
+        assert_eq!(
+            extract_target_currency_override("BTC/USD"),
+            ("BTC/USD".to_string(), "USD".to_string())
+        );
+        assert_eq!(
+            extract_target_currency_override("ETH/BTC"),
+            ("ETH/BTC".to_string(), "USD".to_string())
+        );
+    }
+
+    #[test]
+    fn extract_target_currency_override_still_strips_equity_overrides() {
+        assert_eq!(
+            extract_target_currency_override("AAPL/EUR"),
+            ("AAPL".to_string(), "EUR".to_string())
+        );
+    }
+
+    #[test]
+    fn no_separator_and_no_suffix_match_returns_none() {
+        assert!(parse_crypto_pair("XYZ", DEFAULT_QUOTE_CURRENCIES).is_none());
+    }
+}
@@ -8,6 +8,7 @@ struct PriceReveal {
     reveal_index: usize,
     timestamp: Option<u64>, // For temporal analysis
     source_reliability: f64, // Source quality score (0.0-1.0)
+    liquidity: u128, // Depth/volume figure backing this reveal, for liquidity-weighted aggregation
 }
 
 #[derive(Debug, Clone)]
@@ -22,9 +23,9 @@ struct AggregationResult {
 struct ConfidenceScore {
     percentage: u8,
     bayesian_interval: (u128, u128), // Lower and upper bounds
-    bootstrap_variance: f64,
-    temporal_consistency: f64,
-    cross_validation_score: f64,
+    bootstrap_variance: Fixed,
+    temporal_consistency: Fixed,
+    cross_validation_score: Fixed,
 }
 
 #[derive(Debug, Clone)]
@@ -36,6 +37,8 @@ enum AggregationMethod {
     TimeWeightedAverage,
     VolatilityAdjusted,
     AdaptiveRobust,
+    KernelDensityMode,
+    LiquidityWeightedMedian,
 }
 
 #[derive(Debug, Clone)]
@@ -61,12 +64,328 @@ struct EnhancedStatistics {
     iqr: u128,
     mad: u128, // Median Absolute Deviation
     range: u128,
-    coefficient_of_variation: f64,
-    skewness: f64,
-    kurtosis: f64,
+    coefficient_of_variation: Fixed,
+    skewness: Fixed,
+    kurtosis: Fixed,
     robust_std_dev: u128, // Based on MAD
 }
 
+/// Deterministic Q32.32 fixed-point scalar (32 integer bits, 32 fractional
+/// bits, stored in an `i64`) used for every statistic that used to go
+/// through `f64::sqrt`/`powi`/`ln`. IEEE-754 transcendentals aren't
+/// guaranteed bit-identical across toolchains or targets, so two honest
+/// SEDA executors tallying the same reveals in floating point could
+/// silently compute different `std_dev`/skewness/z-scores and vote
+/// different reveals as outliers, breaking `Process::success` consensus.
+/// Integer fixed-point arithmetic has no such ambiguity. This plays the
+/// same role `fixed::types::I80F48` does in Mango's health engine, just
+/// narrowed to Q32.32: every value this module puts through `Fixed` is a
+/// ratio, z-score, or small multiplier, never a raw `u128` price, so the
+/// 32 fractional bits leave comfortable headroom for `mul`'s widened
+/// `i128` intermediate to never overflow.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Fixed(i64);
+
+impl Fixed {
+    const FRAC_BITS: u32 = 32;
+    const ZERO: Fixed = Fixed(0);
+    const ONE: Fixed = Fixed(1 << Self::FRAC_BITS);
+
+    fn from_i64(value: i64) -> Self {
+        Fixed(value << Self::FRAC_BITS)
+    }
+
+    /// Builds a `Fixed` from an exact integer ratio, e.g.
+    /// `Fixed::from_ratio(diff, std_dev)` for a z-score, without ever
+    /// converting through `f64`.
+    fn from_ratio(numerator: i128, denominator: i128) -> Self {
+        if denominator == 0 {
+            return Fixed::ZERO;
+        }
+        let scaled = (numerator << Self::FRAC_BITS) / denominator;
+        Fixed(scaled.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    }
+
+    fn add(self, other: Fixed) -> Fixed {
+        Fixed(self.0.saturating_add(other.0))
+    }
+
+    fn sub(self, other: Fixed) -> Fixed {
+        Fixed(self.0.saturating_sub(other.0))
+    }
+
+    fn mul(self, other: Fixed) -> Fixed {
+        let product = (self.0 as i128) * (other.0 as i128);
+        let scaled = product >> Self::FRAC_BITS;
+        Fixed(scaled.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    }
+
+    fn div(self, other: Fixed) -> Fixed {
+        if other.0 == 0 {
+            return Fixed::ZERO;
+        }
+        let scaled = ((self.0 as i128) << Self::FRAC_BITS) / (other.0 as i128);
+        Fixed(scaled.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    }
+
+    fn abs(self) -> Fixed {
+        Fixed(self.0.saturating_abs())
+    }
+
+    fn min(self, other: Fixed) -> Fixed {
+        if self.0 <= other.0 { self } else { other }
+    }
+
+    fn max(self, other: Fixed) -> Fixed {
+        if self.0 >= other.0 { self } else { other }
+    }
+
+    fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Cube, used for the skewness moment -- three explicit multiplies
+    /// instead of a generic `powi` so the rounding at each step is pinned
+    /// down exactly.
+    fn cube(self) -> Fixed {
+        self.mul(self).mul(self)
+    }
+
+    /// Fourth power, used for the kurtosis moment.
+    fn fourth_power(self) -> Fixed {
+        let squared = self.mul(self);
+        squared.mul(squared)
+    }
+
+    /// Deterministic fixed-point square root via Newton's method on the
+    /// underlying integer representation, exact to within one ULP of the
+    /// Q32.32 grid -- unlike `f64::sqrt`, every node gets the same bits.
+    fn sqrt(self) -> Fixed {
+        if self.0 <= 0 {
+            return Fixed::ZERO;
+        }
+        // sqrt(x) in Q32.32: the raw mantissa needs to be scaled up by
+        // FRAC_BITS before the integer sqrt so the result lands back in
+        // Q32.32 (integer sqrt of a Q64.64-scaled value is a Q32.32 value).
+        let scaled = (self.0 as u128) << Self::FRAC_BITS;
+        Fixed(isqrt_u128(scaled) as i64)
+    }
+
+    /// Fixed-point `e^x`, "protected" per Zeitgeist's approach: the
+    /// exponent is clamped to a safe range before evaluating so a stale or
+    /// malformed input saturates instead of overflowing or producing a
+    /// meaningless result. Computed via the Taylor series (fixed term
+    /// count -> deterministic), which converges quickly once the input is
+    /// clamped to a small range.
+    fn exp(self) -> Fixed {
+        const MAX_EXPONENT: Fixed = Fixed(20 << Fixed::FRAC_BITS); // e^20 saturates below i64 range
+        const MIN_EXPONENT: Fixed = Fixed(-(20i64) << Fixed::FRAC_BITS);
+        let x = if self.0 > MAX_EXPONENT.0 {
+            MAX_EXPONENT
+        } else if self.0 < MIN_EXPONENT.0 {
+            MIN_EXPONENT
+        } else {
+            self
+        };
+
+        let mut term = Fixed::ONE;
+        let mut sum = Fixed::ONE;
+        for k in 1..=20i64 {
+            term = term.mul(x).div(Fixed::from_i64(k));
+            sum = sum.add(term);
+            if term.0 == 0 {
+                break;
+            }
+        }
+        sum
+    }
+
+    /// Fixed-point natural log via Newton's method on `exp` (solves
+    /// `exp(y) = x` for `y`), seeded from the bit length of `x` for a
+    /// reasonable starting guess. Deterministic (fixed iteration count);
+    /// only meaningful for `self > 0`.
+    fn ln(self) -> Fixed {
+        if self.0 <= 0 {
+            return Fixed::ZERO;
+        }
+
+        // ln(2) ~= 0.6931471805, seed guess = bit_length(x) * ln(2) - frac_bits*ln(2)
+        let ln2 = Fixed(2977044472); // 0.6931471805... in Q32.32
+        let bit_length = 64 - self.0.leading_zeros() as i64;
+        let mut y = Fixed::from_i64(bit_length - Fixed::FRAC_BITS as i64).mul(ln2);
+
+        for _ in 0..25 {
+            let e = y.exp();
+            if e.is_zero() {
+                break;
+            }
+            // Newton step for exp(y) = x: y_{n+1} = y_n + (x - e) / e
+            y = y.add(self.sub(e).div(e));
+        }
+
+        y
+    }
+
+    fn to_f64(self) -> f64 {
+        self.0 as f64 / (1i64 << Self::FRAC_BITS) as f64
+    }
+}
+
+/// Scales a raw `u128` price-scale magnitude (e.g. an IQR) by a `Fixed`
+/// ratio/multiplier without ever promoting the magnitude itself into
+/// `Fixed` -- `Fixed` is sized for ratios, not raw prices, so this widens
+/// to `i128` for the single multiply instead.
+fn scale_u128_by_fixed(value: u128, factor: Fixed) -> u128 {
+    if factor.0 <= 0 {
+        return 0;
+    }
+    let scaled = (value as i128 * factor.0 as i128) >> Fixed::FRAC_BITS;
+    scaled.max(0) as u128
+}
+
+/// Deterministic integer square root (Newton's/Heron's method), exact for
+/// perfect squares and floored otherwise. Used everywhere the old code
+/// called `f64::sqrt` on a price-scale `u128` value, since `f64`
+/// transcendentals aren't guaranteed bit-identical across toolchains.
+fn isqrt_u128(value: u128) -> u128 {
+    if value == 0 {
+        return 0;
+    }
+    let mut x = value;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+/// FNV-1a over the big-endian bytes of every reveal price, used only to
+/// seed the bootstrap PRNG below -- every node aggregating the same price
+/// set derives the identical seed, so the resampling is reproducible
+/// without needing the reveals' raw wire bytes.
+fn hash_reveal_bytes(prices: &[u128]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for &price in prices {
+        for byte in price.to_be_bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    hash
+}
+
+/// SplitMix64, a small deterministic PRNG with no external `rand`
+/// dependency -- same seed always produces the same stream on every node.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        SplitMix64(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform index in `0..bound`.
+    fn next_index(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+/// Real bootstrap resampling (B=1000, with replacement) over `prices`,
+/// seeded from a hash of the reveal prices so every node draws the exact
+/// same resamples. Returns a normalized bootstrap variance (the squared
+/// coefficient of variation of the resampled medians -- a ratio-scale
+/// quantity, safe to hold in `Fixed`) and the 2.5th/97.5th percentile
+/// (percentile method) bootstrap interval.
+///
+/// Dispatches to whichever aggregate statistic `method` actually uses
+/// (see `compute_aggregate`), not just the median, so the interval
+/// reflects the dispersion of the specific estimator being scored.
+fn bootstrap_confidence(prices: &[u128], reveals: &[PriceReveal], method: &AggregationMethod) -> (Fixed, (u128, u128), u8) {
+    if prices.len() < 2 {
+        let only = prices.first().copied().unwrap_or(0);
+        return (Fixed::ZERO, (only, only), 60);
+    }
+
+    const BOOTSTRAP_ITERATIONS: usize = 1000;
+    let n = prices.len();
+    let mut rng = SplitMix64::new(hash_reveal_bytes(prices));
+
+    let mut estimates: Vec<u128> = Vec::with_capacity(BOOTSTRAP_ITERATIONS);
+    for _ in 0..BOOTSTRAP_ITERATIONS {
+        let mut resample_prices: Vec<u128> = Vec::with_capacity(n);
+        let mut resample_reveals: Vec<PriceReveal> = Vec::with_capacity(n);
+        for _ in 0..n {
+            let index = rng.next_index(n);
+            resample_prices.push(prices[index]);
+            if let Some(reveal) = reveals.get(index) {
+                resample_reveals.push(reveal.clone());
+            }
+        }
+        resample_prices.sort();
+        estimates.push(compute_aggregate(&resample_prices, &resample_reveals, method));
+    }
+    estimates.sort();
+
+    let sum: u128 = estimates.iter().sum();
+    let mean_of_estimates = sum / estimates.len() as u128;
+    let variance_sum: u128 = estimates.iter()
+        .map(|&e| {
+            let diff = if e > mean_of_estimates { e - mean_of_estimates } else { mean_of_estimates - e };
+            diff * diff
+        })
+        .sum();
+    let variance = variance_sum / estimates.len() as u128;
+    let std_dev_of_estimates = isqrt_u128(variance);
+
+    let cv_of_estimates = if mean_of_estimates > 0 {
+        Fixed::from_ratio(std_dev_of_estimates as i128, mean_of_estimates as i128)
+    } else {
+        Fixed::ZERO
+    };
+    let bootstrap_variance = cv_of_estimates.mul(cv_of_estimates);
+
+    let lower = percentile(&estimates, 0.025);
+    let upper = percentile(&estimates, 0.975);
+
+    // Feed the CI width back into the confidence percentage -- a tight
+    // interval relative to its midpoint boosts confidence, a wide one
+    // lowers it, replacing the old static base-confidence/sample-size
+    // lookup table.
+    let midpoint = (lower + upper) / 2;
+    let relative_width = if midpoint > 0 { (upper - lower) as f64 / midpoint as f64 } else { 1.0 };
+    let percentage = ((1.0 - relative_width.min(1.0)) * 99.0).max(10.0) as u8;
+
+    (bootstrap_variance, (lower, upper), percentage)
+}
+
+/// Recomputes the aggregate statistic `method` would produce for a given
+/// (already-sorted) resample, so bootstrap confidence reflects the actual
+/// estimator being scored rather than always resampling the median.
+/// `KernelDensityMode` falls back to the median here: rebuilding a full
+/// KDE bandwidth/grid search on every one of the 1000 resamples is not
+/// worth the cost for a confidence estimate.
+fn compute_aggregate(prices: &[u128], reveals: &[PriceReveal], method: &AggregationMethod) -> u128 {
+    match method {
+        AggregationMethod::Median => median(prices),
+        AggregationMethod::TrimmedMean => trimmed_mean(prices, 0.1),
+        AggregationMethod::HodgesLehmann => hodges_lehmann_estimator(prices),
+        AggregationMethod::WeightedConsensus => weighted_consensus(prices),
+        AggregationMethod::TimeWeightedAverage => time_weighted_average_windowed(reveals, TWA_DEFAULT_WINDOW_SECONDS),
+        AggregationMethod::VolatilityAdjusted => volatility_adjusted_weighted_consensus(prices),
+        AggregationMethod::AdaptiveRobust => adaptive_robust(prices),
+        AggregationMethod::KernelDensityMode => median(prices),
+        AggregationMethod::LiquidityWeightedMedian => liquidity_weighted_median(reveals),
+    }
+}
+
 /**
  * Ultra-Enhanced Tally Phase with Military-Grade Statistical Analysis
  * 
@@ -114,12 +433,17 @@ pub fn tally_phase() -> Result<()> {
 
         // Calculate source reliability based on reveal characteristics
         let source_reliability = calculate_source_reliability(price, index, &price_reveals);
+        // The reveal wire format only carries the price itself, so depth
+        // is estimated the same way source_reliability is: a deterministic
+        // heuristic over what we do have, rather than a real venue feed.
+        let liquidity = calculate_liquidity_weight(price, index, &price_reveals);
 
         price_reveals.push(PriceReveal {
             price,
             reveal_index: index,
             timestamp: Some(current_time - (index as u64 * 10)), // Simulate timestamps
             source_reliability,
+            liquidity,
         });
     }
 
@@ -160,7 +484,7 @@ pub fn tally_phase() -> Result<()> {
 
     // Apply enhanced aggregation methods with temporal analysis
     let filtered_price_values: Vec<u128> = filtered_prices.iter().map(|r| r.price).collect();
-    let aggregation_results = apply_enhanced_aggregation_methods(&filtered_price_values, &stats, &outlier_metadata);
+    let aggregation_results = apply_enhanced_aggregation_methods(&filtered_price_values, &filtered_prices, &stats, &outlier_metadata);
     
     // Select the best aggregation result using advanced scoring
     let final_result = select_optimal_aggregation(&aggregation_results, &filtered_price_values);
@@ -175,9 +499,9 @@ pub fn tally_phase() -> Result<()> {
     log!("   • Bayesian Interval: (${:.6}, ${:.6})", 
           final_result.confidence.bayesian_interval.0 as f64 / 1_000_000.0,
           final_result.confidence.bayesian_interval.1 as f64 / 1_000_000.0);
-    log!("   • Bootstrap Variance: {:.8}", final_result.confidence.bootstrap_variance);
-    log!("   • Temporal Consistency: {:.2}%", final_result.confidence.temporal_consistency * 100.0);
-    log!("   • Cross-Validation Score: {:.2}%", final_result.confidence.cross_validation_score * 100.0);
+    log!("   • Bootstrap Variance: {:.8}", final_result.confidence.bootstrap_variance.to_f64());
+    log!("   • Temporal Consistency: {:.2}%", final_result.confidence.temporal_consistency.to_f64() * 100.0);
+    log!("   • Cross-Validation Score: {:.2}%", final_result.confidence.cross_validation_score.to_f64() * 100.0);
     log!("   • Data Points Used: {}/{}", final_result.metadata.sample_size, prices.len());
     log!("   • Time Span: {}s", final_result.metadata.time_span_seconds);
     log!("   • Volatility Score: {:.4}", final_result.metadata.volatility_score);
@@ -281,34 +605,76 @@ fn calculate_source_reliability(price: u128, reveal_index: usize, existing_revea
     reliability.max(0.1).min(1.0)
 }
 
+/// Depth/volume figure backing a reveal. The wire format carries only the
+/// price, so there is no real order-book depth to read; this derives a
+/// deterministic proxy instead, favoring reveals that land close to the
+/// emerging consensus (closer-to-median prices look like deep, liquid
+/// books, while outliers look like thin ones) and penalizing later reveals
+/// the same way `calculate_source_reliability` does.
+fn calculate_liquidity_weight(price: u128, reveal_index: usize, existing_reveals: &[PriceReveal]) -> u128 {
+    const BASE_LIQUIDITY: u128 = 1_000_000;
+
+    let mut liquidity = BASE_LIQUIDITY;
+
+    if !existing_reveals.is_empty() {
+        let existing_prices: Vec<u128> = existing_reveals.iter().map(|r| r.price).collect();
+        let median_existing = median(&existing_prices);
+
+        let deviation_ratio = if median_existing > 0 {
+            let diff = if price > median_existing { price - median_existing } else { median_existing - price };
+            diff as f64 / median_existing as f64
+        } else {
+            0.0
+        };
+
+        // Reward prices close to the emerging consensus, penalize outliers.
+        let proximity_factor = (1.0 - deviation_ratio.min(1.0)).max(0.1);
+        liquidity = scale_u128_by_fixed(liquidity, Fixed::from_ratio((proximity_factor * 1_000_000.0).round() as i128, 1_000_000));
+    }
+
+    // Slight order-based decay, matching the reliability heuristic above.
+    let order_factor = (1.0 - (reveal_index as f64 * 0.02).min(0.3)).max(0.1);
+    liquidity = scale_u128_by_fixed(liquidity, Fixed::from_ratio((order_factor * 1_000_000.0).round() as i128, 1_000_000));
+
+    liquidity.max(1)
+}
+
 fn calculate_enhanced_statistics(prices: &[u128]) -> EnhancedStatistics {
     let count = prices.len();
     let min = *prices.first().unwrap();
     let max = *prices.last().unwrap();
-    let median_value = median(prices);
+
+    // Quantiles come from a t-digest rather than a direct sort-and-
+    // interpolate pass, so this keeps working in bounded memory no matter
+    // how large the reveal set grows.
+    let digest = TDigest::from_prices(prices);
+    let median_value = digest.estimate_quantile(0.5);
     let range = max - min;
-    
-    // Calculate mean
-    let sum: u128 = prices.iter().sum();
-    let mean = sum / count as u128;
-    
-    // Calculate standard deviation
-    let variance_sum: u128 = prices.iter()
-        .map(|&price| {
-            let diff = if price > mean { price - mean } else { mean - price };
-            (diff as u64 * diff as u64) as u128
-        })
-        .sum();
-    
-    let variance = variance_sum / count as u128;
-    let std_dev = (variance as f64).sqrt() as u128;
-    
-    // Calculate quartiles
-    let q1_index = count / 4;
-    let q3_index = (3 * count) / 4;
-    let q1 = prices[q1_index.min(count - 1)];
-    let q3 = prices[q3_index.min(count - 1)];
-    let iqr = q3 - q1;
+
+    // Calculate mean and variance in a single Welford/West pass. The old
+    // two-step version (sum the prices, then sum squared deviations)
+    // narrowed each deviation to `u64` before squaring, which silently
+    // overflowed for prices near the top of the valid range. Welford's
+    // update keeps `delta`/`delta2` bounded by the raw price range, so
+    // their product -- accumulated into `m2` -- always fits in `i128`.
+    let mut mean_acc: i128 = 0;
+    let mut m2: i128 = 0;
+    for (i, &price) in prices.iter().enumerate() {
+        let n = i as i128 + 1;
+        let delta = price as i128 - mean_acc;
+        mean_acc += delta / n;
+        let delta2 = price as i128 - mean_acc;
+        m2 += delta * delta2;
+    }
+    let mean = mean_acc as u128;
+
+    let variance = (m2 / count as i128).max(0) as u128;
+    let std_dev = isqrt_u128(variance);
+
+    // Calculate quartiles from the same t-digest used for the median.
+    let q1 = digest.estimate_quantile(0.25);
+    let q3 = digest.estimate_quantile(0.75);
+    let iqr = q3.saturating_sub(q1);
     
     // Calculate Median Absolute Deviation (MAD)
     let mut deviations: Vec<u128> = prices.iter()
@@ -317,40 +683,44 @@ fn calculate_enhanced_statistics(prices: &[u128]) -> EnhancedStatistics {
     deviations.sort();
     let mad = median(&deviations);
     
-    // Calculate robust standard deviation (1.4826 * MAD)
-    let robust_std_dev = ((mad as f64) * 1.4826) as u128;
-    
-    // Calculate skewness (measure of asymmetry)
+    // Calculate robust standard deviation (1.4826 * MAD), done with an
+    // integer-scaled constant (14826/10000) so no float ever enters it.
+    let robust_std_dev = (mad * 14826) / 10000;
+
+    // Calculate skewness (measure of asymmetry) in fixed-point: every
+    // z-score and its cube is computed without ever touching `f64`, so
+    // every executor derives the identical `Fixed` bit pattern.
     let skewness = if std_dev > 0 {
-        let skew_sum: f64 = prices.iter()
-            .map(|&price| {
-                let z_score = (price as f64 - mean as f64) / std_dev as f64;
-                z_score.powi(3)
-            })
-            .sum();
-        skew_sum / count as f64
+        let mut skew_sum = Fixed::ZERO;
+        for &price in prices {
+            let diff: i128 = price as i128 - mean as i128;
+            let z_score = Fixed::from_ratio(diff, std_dev as i128);
+            skew_sum = skew_sum.add(z_score.cube());
+        }
+        skew_sum.div(Fixed::from_i64(count as i64))
     } else {
-        0.0
+        Fixed::ZERO
     };
-    
-    // Calculate kurtosis (measure of tail heaviness)
+
+    // Calculate kurtosis (measure of tail heaviness), same fixed-point
+    // approach as skewness above.
     let kurtosis = if std_dev > 0 {
-        let kurt_sum: f64 = prices.iter()
-            .map(|&price| {
-                let z_score = (price as f64 - mean as f64) / std_dev as f64;
-                z_score.powi(4)
-            })
-            .sum();
-        (kurt_sum / count as f64) - 3.0 // Excess kurtosis
+        let mut kurt_sum = Fixed::ZERO;
+        for &price in prices {
+            let diff: i128 = price as i128 - mean as i128;
+            let z_score = Fixed::from_ratio(diff, std_dev as i128);
+            kurt_sum = kurt_sum.add(z_score.fourth_power());
+        }
+        kurt_sum.div(Fixed::from_i64(count as i64)).sub(Fixed::from_i64(3)) // Excess kurtosis
     } else {
-        0.0
+        Fixed::ZERO
     };
-    
+
     // Calculate coefficient of variation
     let coefficient_of_variation = if mean > 0 {
-        (std_dev as f64 / mean as f64) * 100.0
+        Fixed::from_ratio(std_dev as i128, mean as i128).mul(Fixed::from_i64(100))
     } else {
-        0.0
+        Fixed::ZERO
     };
 
     EnhancedStatistics {
@@ -386,9 +756,9 @@ fn log_enhanced_statistical_summary(stats: &EnhancedStatistics) {
     log!("   • Q3: {} (${:.6})", stats.q3, stats.q3 as f64 / 1_000_000.0);
     log!("   • IQR: {} (${:.6})", stats.iqr, stats.iqr as f64 / 1_000_000.0);
     log!("   • MAD: {} (${:.6})", stats.mad, stats.mad as f64 / 1_000_000.0);
-    log!("   • CV: {:.2}%", stats.coefficient_of_variation);
-    log!("   • Skewness: {:.4}", stats.skewness);
-    log!("   • Kurtosis: {:.4}", stats.kurtosis);
+    log!("   • CV: {:.2}%", stats.coefficient_of_variation.to_f64());
+    log!("   • Skewness: {:.4}", stats.skewness.to_f64());
+    log!("   • Kurtosis: {:.4}", stats.kurtosis.to_f64());
 }
 
 fn ultra_advanced_outlier_detection(price_reveals: &[PriceReveal], stats: &EnhancedStatistics) -> (Vec<PriceReveal>, AggregationMetadata) {
@@ -436,10 +806,15 @@ fn ultra_advanced_outlier_detection(price_reveals: &[PriceReveal], stats: &Enhan
     let reliability_filtered = reliability_weighted_outlier_detection(price_reveals);
     methods_used.push("Reliability-Weighted".to_string());
     log!("   • Reliability Weighted: {}/{} points retained", reliability_filtered.len(), price_reveals.len());
-    
+
+    // Method 9: Shape-aware trimming (asymmetric, skew/kurtosis-adaptive)
+    let (shape_filtered, (shape_lower_cut, shape_upper_cut)) = shape_aware_outlier_detection(&prices, stats);
+    methods_used.push(format!("Shape-Aware(cuts=[{}, {}])", shape_lower_cut, shape_upper_cut));
+    log!("   • Shape-Aware: {}/{} points retained (cuts [{}, {}])", shape_filtered.len(), prices.len(), shape_lower_cut, shape_upper_cut);
+
     // Intelligent consensus between methods
     let mut consensus_scores: HashMap<u128, i32> = HashMap::new();
-    
+
     // Score each price based on how many methods accept it
     for &price in &prices {
         let mut score = 0;
@@ -450,23 +825,24 @@ fn ultra_advanced_outlier_detection(price_reveals: &[PriceReveal], stats: &Enhan
         if isolation_filtered.contains(&price) { score += 1; }
         if mad_filtered.contains(&price) { score += 1; }
         if tukey_filtered.contains(&price) { score += 1; }
+        if shape_filtered.contains(&price) { score += 1; }
         consensus_scores.insert(price, score);
     }
-    
+
     // Also score reliability-weighted results
     for reveal in &reliability_filtered {
         if let Some(score) = consensus_scores.get_mut(&reveal.price) {
             *score += 1;
         }
     }
-    
-    // Determine threshold based on data characteristics
+
+    // Determine threshold based on data characteristics (out of 9 methods)
     let consensus_threshold = if prices.len() <= 3 {
-        4 // Be more lenient for small datasets
-    } else if stats.coefficient_of_variation > 20.0 {
-        5 // Higher threshold for volatile data
+        5 // Be more lenient for small datasets
+    } else if stats.coefficient_of_variation > Fixed::from_i64(20) {
+        6 // Higher threshold for volatile data
     } else {
-        6 // Standard threshold for stable data
+        7 // Standard threshold for stable data
     };
     
     // Filter based on consensus
@@ -481,11 +857,11 @@ fn ultra_advanced_outlier_detection(price_reveals: &[PriceReveal], stats: &Enhan
     
     // Fallback strategy if too conservative
     let result_filtered = if final_filtered.len() < prices.len() / 3 {
-        log!("   • Too conservative, using majority consensus (threshold: 4)");
+        log!("   • Too conservative, using majority consensus (threshold: 5)");
         price_reveals.iter()
             .filter(|reveal| {
                 consensus_scores.get(&reveal.price)
-                    .map(|&score| score >= 4)
+                    .map(|&score| score >= 5)
                     .unwrap_or(false)
             })
             .cloned()
@@ -516,25 +892,27 @@ fn ultra_advanced_outlier_detection(price_reveals: &[PriceReveal], stats: &Enhan
         ) {
             if first > last { first - last } else { last - first }
         } else { 0 },
-        volatility_score: stats.coefficient_of_variation / 100.0,
+        volatility_score: stats.coefficient_of_variation.to_f64() / 100.0,
         consensus_threshold: consensus_threshold as f64,
         outlier_methods_used: methods_used,
     };
-    
+
     (final_result, metadata)
 }
 
 // Enhanced outlier detection methods
 fn enhanced_iqr_outlier_detection(prices: &[u128], stats: &EnhancedStatistics) -> Vec<u128> {
-    // Adaptive multiplier based on sample size and distribution
-    let base_multiplier = if prices.len() <= 5 { 2.5 } else { 1.5 };
-    let skew_adjustment = (stats.skewness.abs() * 0.2).min(0.5);
-    let kurtosis_adjustment = (stats.kurtosis.abs() * 0.1).min(0.3);
-    let multiplier = base_multiplier + skew_adjustment + kurtosis_adjustment;
-    
-    let lower_bound = stats.q1.saturating_sub((stats.iqr as f64 * multiplier) as u128);
-    let upper_bound = stats.q3.saturating_add((stats.iqr as f64 * multiplier) as u128);
-    
+    // Adaptive multiplier based on sample size and distribution, computed
+    // in fixed-point so the fence every executor derives is bit-identical.
+    let base_multiplier = if prices.len() <= 5 { Fixed::from_ratio(25, 10) } else { Fixed::from_ratio(15, 10) };
+    let skew_adjustment = stats.skewness.abs().mul(Fixed::from_ratio(2, 10)).min(Fixed::from_ratio(5, 10));
+    let kurtosis_adjustment = stats.kurtosis.abs().mul(Fixed::from_ratio(1, 10)).min(Fixed::from_ratio(3, 10));
+    let multiplier = base_multiplier.add(skew_adjustment).add(kurtosis_adjustment);
+
+    let offset = scale_u128_by_fixed(stats.iqr, multiplier);
+    let lower_bound = stats.q1.saturating_sub(offset);
+    let upper_bound = stats.q3.saturating_add(offset);
+
     prices.iter()
         .filter(|&&price| price >= lower_bound && price <= upper_bound)
         .cloned()
@@ -542,17 +920,17 @@ fn enhanced_iqr_outlier_detection(prices: &[u128], stats: &EnhancedStatistics) -
 }
 
 fn modified_zscore_outlier_detection(prices: &[u128], stats: &EnhancedStatistics) -> Vec<u128> {
-    let threshold = 3.5; // Modified Z-score threshold
-    let mad_factor = 1.4826; // Constant for normal distribution
-    
+    let threshold = Fixed::from_ratio(35, 10); // Modified Z-score threshold
+    let mad_factor = Fixed::from_ratio(14826, 10000); // Constant for normal distribution
+
     if stats.mad == 0 {
         return prices.to_vec(); // No variation
     }
-    
+
     prices.iter()
         .filter(|&&price| {
-            let deviation = if price > stats.median { price - stats.median } else { stats.median - price };
-            let modified_zscore = mad_factor * (deviation as f64) / (stats.mad as f64);
+            let deviation: i128 = if price > stats.median { price - stats.median } else { stats.median - price } as i128;
+            let modified_zscore = mad_factor.mul(Fixed::from_ratio(deviation, stats.mad as i128));
             modified_zscore <= threshold
         })
         .cloned()
@@ -566,26 +944,26 @@ fn grubbs_outlier_detection(prices: &[u128], stats: &EnhancedStatistics) -> Vec<
     
     // Grubbs' critical values (simplified for common sample sizes)
     let critical_value = match prices.len() {
-        3..=10 => 2.2,
-        11..=20 => 2.7,
-        21..=50 => 3.1,
-        _ => 3.5,
+        3..=10 => Fixed::from_ratio(22, 10),
+        11..=20 => Fixed::from_ratio(27, 10),
+        21..=50 => Fixed::from_ratio(31, 10),
+        _ => Fixed::from_ratio(35, 10),
     };
-    
+
     let mut filtered = Vec::new();
     for &price in prices {
         let z_score = if stats.std_dev > 0 {
-            let diff = if price > stats.mean { price - stats.mean } else { stats.mean - price };
-            diff as f64 / stats.std_dev as f64
+            let diff: i128 = if price > stats.mean { price - stats.mean } else { stats.mean - price } as i128;
+            Fixed::from_ratio(diff, stats.std_dev as i128)
         } else {
-            0.0
+            Fixed::ZERO
         };
-        
+
         if z_score <= critical_value {
             filtered.push(price);
         }
     }
-    
+
     filtered
 }
 
@@ -596,40 +974,40 @@ fn dixon_q_outlier_detection(prices: &[u128]) -> Vec<u128> {
     
     // Dixon's Q critical values (simplified)
     let critical_q = match prices.len() {
-        3..=7 => 0.7,
-        8..=10 => 0.54,
-        11..=13 => 0.48,
-        14..=30 => 0.43,
-        _ => 0.35,
+        3..=7 => Fixed::from_ratio(7, 10),
+        8..=10 => Fixed::from_ratio(54, 100),
+        11..=13 => Fixed::from_ratio(48, 100),
+        14..=30 => Fixed::from_ratio(43, 100),
+        _ => Fixed::from_ratio(35, 100),
     };
-    
+
     let n = prices.len();
-    let range = (prices[n-1] - prices[0]) as f64;
-    
-    if range == 0.0 {
+    let range: i128 = (prices[n-1] - prices[0]) as i128;
+
+    if range == 0 {
         return prices.to_vec();
     }
-    
+
     let mut filtered = prices.to_vec();
-    
+
     // Check lowest value
-    let q_low = (prices[1] - prices[0]) as f64 / range;
+    let q_low = Fixed::from_ratio((prices[1] - prices[0]) as i128, range);
     if q_low > critical_q {
         filtered.remove(0);
     }
-    
+
     // Check highest value (on potentially modified array)
     if filtered.len() > 2 {
         let n_filtered = filtered.len();
-        let range_filtered = (filtered[n_filtered-1] - filtered[0]) as f64;
-        if range_filtered > 0.0 {
-            let q_high = (filtered[n_filtered-1] - filtered[n_filtered-2]) as f64 / range_filtered;
+        let range_filtered: i128 = (filtered[n_filtered-1] - filtered[0]) as i128;
+        if range_filtered > 0 {
+            let q_high = Fixed::from_ratio((filtered[n_filtered-1] - filtered[n_filtered-2]) as i128, range_filtered);
             if q_high > critical_q {
                 filtered.pop();
             }
         }
     }
-    
+
     filtered
 }
 
@@ -642,13 +1020,13 @@ fn isolation_forest_outlier_detection(prices: &[u128], stats: &EnhancedStatistic
         return prices.to_vec();
     }
     
-    let mut isolation_scores: Vec<(u128, f64)> = Vec::new();
-    
+    let mut isolation_scores: Vec<(u128, Fixed)> = Vec::new();
+
     for &price in prices {
         // Distance from median
-        let distance = if price > median_price { price - median_price } else { median_price - price };
-        let normalized_distance = distance as f64 / mad as f64;
-        
+        let distance: i128 = if price > median_price { price - median_price } else { median_price - price } as i128;
+        let normalized_distance = Fixed::from_ratio(distance, mad as i128);
+
         // Local density (count of nearby points)
         let tolerance = mad / 2;
         let nearby_count = prices.iter()
@@ -657,18 +1035,18 @@ fn isolation_forest_outlier_detection(prices: &[u128], stats: &EnhancedStatistic
                 diff <= tolerance
             })
             .count();
-        
-        let local_density = nearby_count as f64 / prices.len() as f64;
-        
+
+        let local_density = Fixed::from_ratio(nearby_count as i128, prices.len() as i128);
+
         // Isolation score: higher score = more likely outlier
-        let isolation_score = normalized_distance / (local_density + 0.1);
+        let isolation_score = normalized_distance.div(local_density.add(Fixed::from_ratio(1, 10)));
         isolation_scores.push((price, isolation_score));
     }
-    
+
     // Remove points with highest isolation scores
     isolation_scores.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
-    
-    let threshold = if prices.len() > 10 { 2.0 } else { 3.0 };
+
+    let threshold = if prices.len() > 10 { Fixed::from_i64(2) } else { Fixed::from_i64(3) };
     isolation_scores.iter()
         .filter(|(_, score)| *score <= threshold)
         .map(|(price, _)| *price)
@@ -676,18 +1054,18 @@ fn isolation_forest_outlier_detection(prices: &[u128], stats: &EnhancedStatistic
 }
 
 fn enhanced_mad_outlier_detection(prices: &[u128], stats: &EnhancedStatistics) -> Vec<u128> {
-    let threshold = 3.0; // Enhanced threshold
-    let mad_factor = 1.4826;
-    
+    let threshold = Fixed::from_i64(3); // Enhanced threshold
+    let mad_factor = Fixed::from_ratio(14826, 10000);
+
     if stats.mad == 0 {
         return prices.to_vec();
     }
-    
+
     // Use robust MAD for both median and MAD calculations
     prices.iter()
         .filter(|&&price| {
-            let deviation = if price > stats.median { price - stats.median } else { stats.median - price };
-            let modified_zscore = mad_factor * (deviation as f64) / (stats.mad as f64);
+            let deviation: i128 = if price > stats.median { price - stats.median } else { stats.median - price } as i128;
+            let modified_zscore = mad_factor.mul(Fixed::from_ratio(deviation, stats.mad as i128));
             modified_zscore <= threshold
         })
         .cloned()
@@ -695,39 +1073,112 @@ fn enhanced_mad_outlier_detection(prices: &[u128], stats: &EnhancedStatistics) -
 }
 
 fn tukey_outlier_detection(prices: &[u128], stats: &EnhancedStatistics) -> Vec<u128> {
-    // Adaptive Tukey fences based on data characteristics
-    let base_multiplier = 1.5;
-    let volatility_adjustment = (stats.coefficient_of_variation / 100.0).min(1.0);
-    let multiplier = base_multiplier + volatility_adjustment;
-    
-    let lower_fence = stats.q1.saturating_sub((stats.iqr as f64 * multiplier) as u128);
-    let upper_fence = stats.q3.saturating_add((stats.iqr as f64 * multiplier) as u128);
-    
+    // Adaptive Tukey fences based on data characteristics, computed in
+    // fixed-point for the same cross-executor determinism as the IQR method.
+    let base_multiplier = Fixed::from_ratio(15, 10);
+    let volatility_adjustment = stats.coefficient_of_variation.div(Fixed::from_i64(100)).min(Fixed::ONE);
+    let multiplier = base_multiplier.add(volatility_adjustment);
+
+    let offset = scale_u128_by_fixed(stats.iqr, multiplier);
+    let lower_fence = stats.q1.saturating_sub(offset);
+    let upper_fence = stats.q3.saturating_add(offset);
+
     prices.iter()
         .filter(|&&price| price >= lower_fence && price <= upper_fence)
         .cloned()
         .collect()
 }
 
+/// Shape-aware outlier trimming: instead of the symmetric fences used by
+/// the IQR/Tukey/MAD methods above, adapts to the sample's actual skew and
+/// tail weight (via the streaming `RunningStats` moments). A large `|g1|`
+/// means one tail is doing the dragging (e.g. a lagging venue), so that
+/// side gets trimmed harder while the other stays close to the MAD fence;
+/// a large `g2` (fat tails / a single spike) widens the MAD multiplier
+/// instead so a legitimate heavy-tailed bulk isn't shaved down. Returns the
+/// surviving prices plus the asymmetric `(lower_cut, upper_cut)` so the
+/// caller can record which side was trimmed and why.
+fn shape_aware_outlier_detection(prices: &[u128], stats: &EnhancedStatistics) -> (Vec<u128>, (u128, u128)) {
+    if prices.len() < 3 || stats.mad == 0 {
+        return (prices.to_vec(), (stats.min, stats.max));
+    }
+
+    // `RunningStats::skewness`/`kurtosis` go through `f64::powf`, which
+    // (unlike `sqrt`) isn't guaranteed bit-identical across executors'
+    // libm, so they're diagnostic-only here and never gate which prices
+    // survive. The actual vote below uses `stats.skewness`/`stats.kurtosis`,
+    // the `Fixed` z-score-cube/fourth-power pass from
+    // `calculate_enhanced_statistics`, so every node casts the same vote.
+    let mut running = RunningStats::new();
+    for &price in prices {
+        running.update(price);
+    }
+    log!("   • Shape-Aware (diagnostic, non-consensus): g1={:.3} g2={:.3}", running.skewness(), running.kurtosis());
+
+    let g1 = stats.skewness;
+    let g2 = stats.kurtosis;
+
+    let base_mad_multiplier = Fixed::from_i64(3);
+    let kurtosis_widening = if g2.abs() > Fixed::from_i64(3) { Fixed::from_ratio(15, 10) } else { Fixed::ONE };
+    let mad_multiplier = base_mad_multiplier.mul(kurtosis_widening);
+
+    // Asymmetric trim bias: the heavy-tail side keeps the full multiplier,
+    // the light-tail side gets trimmed harder (a smaller multiplier).
+    let (lower_bias, upper_bias) = if g1 > Fixed::ONE {
+        (Fixed::from_ratio(7, 10), Fixed::ONE) // right-skewed: heavy right tail
+    } else if g1 < Fixed::ZERO.sub(Fixed::ONE) {
+        (Fixed::ONE, Fixed::from_ratio(7, 10)) // left-skewed: heavy left tail
+    } else {
+        (Fixed::ONE, Fixed::ONE)
+    };
+
+    let lower_offset = scale_u128_by_fixed(stats.mad, mad_multiplier.mul(lower_bias));
+    let upper_offset = scale_u128_by_fixed(stats.mad, mad_multiplier.mul(upper_bias));
+
+    let lower_cut = stats.median.saturating_sub(lower_offset);
+    let upper_cut = stats.median.saturating_add(upper_offset);
+
+    let retained: Vec<u128> = prices.iter()
+        .filter(|&&price| price >= lower_cut && price <= upper_cut)
+        .cloned()
+        .collect();
+
+    (retained, (lower_cut, upper_cut))
+}
+
 fn reliability_weighted_outlier_detection(price_reveals: &[PriceReveal]) -> Vec<PriceReveal> {
-    // Filter based on source reliability scores
-    let avg_reliability: f64 = price_reveals.iter().map(|r| r.source_reliability).sum::<f64>() / price_reveals.len() as f64;
-    let reliability_threshold = (avg_reliability * 0.7).max(0.3); // At least 70% of average, minimum 30%
-    
+    // Compose source reliability with liquidity: a reveal backed by
+    // above-average depth gets a boost, thin-book reveals get discounted,
+    // so a deep but slightly-stale venue isn't filtered out purely on the
+    // reliability score while a thin, barely-reliable one is.
+    let avg_liquidity: f64 = price_reveals.iter().map(|r| r.liquidity as f64).sum::<f64>() / price_reveals.len() as f64;
+
+    let effective_score = |reveal: &PriceReveal| -> f64 {
+        if avg_liquidity > 0.0 {
+            let liquidity_factor = (reveal.liquidity as f64 / avg_liquidity).clamp(0.5, 1.5);
+            reveal.source_reliability * liquidity_factor
+        } else {
+            reveal.source_reliability
+        }
+    };
+
+    let avg_effective_score: f64 = price_reveals.iter().map(effective_score).sum::<f64>() / price_reveals.len() as f64;
+    let reliability_threshold = (avg_effective_score * 0.7).max(0.3); // At least 70% of average, minimum 30%
+
     price_reveals.iter()
-        .filter(|reveal| reveal.source_reliability >= reliability_threshold)
+        .filter(|reveal| effective_score(reveal) >= reliability_threshold)
         .cloned()
         .collect()
 }
 
-fn apply_enhanced_aggregation_methods(prices: &[u128], stats: &EnhancedStatistics, outlier_metadata: &AggregationMetadata) -> Vec<AggregationResult> {
+fn apply_enhanced_aggregation_methods(prices: &[u128], reveals: &[PriceReveal], stats: &EnhancedStatistics, outlier_metadata: &AggregationMetadata) -> Vec<AggregationResult> {
     let mut results = Vec::new();
     
     // Method 1: Standard Median
     let median_result = AggregationResult {
         price: median(prices),
         method: AggregationMethod::Median,
-        confidence: calculate_method_confidence(prices, AggregationMethod::Median),
+        confidence: calculate_method_confidence(prices, reveals, AggregationMethod::Median),
         metadata: AggregationMetadata {
             sample_size: prices.len(),
             outliers_removed: 0,
@@ -744,7 +1195,7 @@ fn apply_enhanced_aggregation_methods(prices: &[u128], stats: &EnhancedStatistic
         let trimmed_result = AggregationResult {
             price: trimmed_mean(prices, 0.1),
             method: AggregationMethod::TrimmedMean,
-            confidence: calculate_method_confidence(prices, AggregationMethod::TrimmedMean),
+            confidence: calculate_method_confidence(prices, reveals, AggregationMethod::TrimmedMean),
             metadata: AggregationMetadata {
                 sample_size: prices.len(),
                 outliers_removed: (prices.len() as f64 * 0.2) as usize,
@@ -762,7 +1213,7 @@ fn apply_enhanced_aggregation_methods(prices: &[u128], stats: &EnhancedStatistic
         let hl_result = AggregationResult {
             price: hodges_lehmann_estimator(prices),
             method: AggregationMethod::HodgesLehmann,
-            confidence: calculate_method_confidence(prices, AggregationMethod::HodgesLehmann),
+            confidence: calculate_method_confidence(prices, reveals, AggregationMethod::HodgesLehmann),
             metadata: AggregationMetadata {
                 sample_size: prices.len(),
                 outliers_removed: 0,
@@ -780,7 +1231,7 @@ fn apply_enhanced_aggregation_methods(prices: &[u128], stats: &EnhancedStatistic
         let weighted_result = AggregationResult {
             price: weighted_consensus(prices),
             method: AggregationMethod::WeightedConsensus,
-            confidence: calculate_method_confidence(prices, AggregationMethod::WeightedConsensus),
+            confidence: calculate_method_confidence(prices, reveals, AggregationMethod::WeightedConsensus),
             metadata: AggregationMetadata {
                 sample_size: prices.len(),
                 outliers_removed: 0,
@@ -793,17 +1244,35 @@ fn apply_enhanced_aggregation_methods(prices: &[u128], stats: &EnhancedStatistic
         results.push(weighted_result);
     }
     
-    // Method 5: Time-Weighted Average
+    // Method 5: Time-Weighted Average (real exponential decay over the
+    // reveals' actual timestamps, combined with source reliability, over a
+    // sliding window so reveals from venues that have gone stale drop out
+    // of consensus entirely instead of lingering at a negligible weight)
+    let twa_price = time_weighted_average_windowed(reveals, TWA_DEFAULT_WINDOW_SECONDS);
+    let time_span_seconds = match (reveals.iter().filter_map(|r| r.timestamp).min(), reveals.iter().filter_map(|r| r.timestamp).max()) {
+        (Some(min_ts), Some(max_ts)) => max_ts.saturating_sub(min_ts),
+        _ => 0,
+    };
+    // The i.i.d. bootstrap above assumes independent draws, but reveals
+    // from the same venues arriving close together are serially
+    // correlated, so its interval understates the true uncertainty here.
+    // Replace it with a long-run-variance (Newey-West style) interval
+    // that accounts for that autocorrelation.
+    let mut twa_confidence = calculate_method_confidence(prices, reveals, AggregationMethod::TimeWeightedAverage);
+    let (autocorrelation_interval, temporal_consistency) = autocorrelation_corrected_interval(reveals);
+    twa_confidence.bayesian_interval = autocorrelation_interval;
+    twa_confidence.temporal_consistency = temporal_consistency;
+
     let twa_result = AggregationResult {
-        price: time_weighted_average(prices),
+        price: twa_price,
         method: AggregationMethod::TimeWeightedAverage,
-        confidence: calculate_method_confidence(prices, AggregationMethod::TimeWeightedAverage),
+        confidence: twa_confidence,
         metadata: AggregationMetadata {
             sample_size: prices.len(),
             outliers_removed: 0,
-            time_span_seconds: 120, // Default time span for simulation
-            volatility_score: stats.coefficient_of_variation / 100.0,
-            consensus_threshold: calculate_consensus_threshold(prices, time_weighted_average(prices)),
+            time_span_seconds,
+            volatility_score: stats.coefficient_of_variation.to_f64() / 100.0,
+            consensus_threshold: calculate_consensus_threshold(prices, twa_price),
             outlier_methods_used: Vec::new(),
         },
     };
@@ -814,12 +1283,12 @@ fn apply_enhanced_aggregation_methods(prices: &[u128], stats: &EnhancedStatistic
         let vwa_result = AggregationResult {
             price: volatility_adjusted_weighted_consensus(prices),
             method: AggregationMethod::VolatilityAdjusted,
-            confidence: calculate_method_confidence(prices, AggregationMethod::VolatilityAdjusted),
+            confidence: calculate_method_confidence(prices, reveals, AggregationMethod::VolatilityAdjusted),
             metadata: AggregationMetadata {
                 sample_size: prices.len(),
                 outliers_removed: 0,
                 time_span_seconds: 120,
-                volatility_score: stats.coefficient_of_variation / 100.0,
+                volatility_score: stats.coefficient_of_variation.to_f64() / 100.0,
                 consensus_threshold: calculate_consensus_threshold(prices, volatility_adjusted_weighted_consensus(prices)),
                 outlier_methods_used: Vec::new(),
             },
@@ -832,7 +1301,7 @@ fn apply_enhanced_aggregation_methods(prices: &[u128], stats: &EnhancedStatistic
         let ar_result = AggregationResult {
             price: adaptive_robust(prices),
             method: AggregationMethod::AdaptiveRobust,
-            confidence: calculate_method_confidence(prices, AggregationMethod::AdaptiveRobust),
+            confidence: calculate_method_confidence(prices, reveals, AggregationMethod::AdaptiveRobust),
             metadata: AggregationMetadata {
                 sample_size: prices.len(),
                 outliers_removed: 0,
@@ -844,7 +1313,47 @@ fn apply_enhanced_aggregation_methods(prices: &[u128], stats: &EnhancedStatistic
         };
         results.push(ar_result);
     }
-    
+
+    // Method 8: Kernel Density Mode
+    if prices.len() >= 3 {
+        let (kde_price, mode_gap) = kde_mode(prices, stats);
+        let mut kde_confidence = calculate_method_confidence(prices, reveals, AggregationMethod::KernelDensityMode);
+        kde_confidence.temporal_consistency = mode_gap;
+        let kde_result = AggregationResult {
+            price: kde_price,
+            method: AggregationMethod::KernelDensityMode,
+            confidence: kde_confidence,
+            metadata: AggregationMetadata {
+                sample_size: prices.len(),
+                outliers_removed: 0,
+                time_span_seconds: 0,
+                volatility_score: stats.coefficient_of_variation.to_f64() / 100.0,
+                consensus_threshold: calculate_consensus_threshold(prices, kde_price),
+                outlier_methods_used: Vec::new(),
+            },
+        };
+        results.push(kde_result);
+    }
+
+    // Method 9: Liquidity-Weighted Median
+    if prices.len() >= 2 {
+        let lwm_price = liquidity_weighted_median(reveals);
+        let lwm_result = AggregationResult {
+            price: lwm_price,
+            method: AggregationMethod::LiquidityWeightedMedian,
+            confidence: calculate_method_confidence(prices, reveals, AggregationMethod::LiquidityWeightedMedian),
+            metadata: AggregationMetadata {
+                sample_size: prices.len(),
+                outliers_removed: 0,
+                time_span_seconds: 0,
+                volatility_score: stats.coefficient_of_variation.to_f64() / 100.0,
+                consensus_threshold: calculate_consensus_threshold(prices, lwm_price),
+                outlier_methods_used: Vec::new(),
+            },
+        };
+        results.push(lwm_result);
+    }
+
     log!("🔬 Ultra-Enhanced Aggregation Methods Applied: {}", results.len());
     for result in &results {
         log!("   • {:?}: {} (Confidence: {}%, Consensus: {:.1}%)", 
@@ -854,33 +1363,15 @@ fn apply_enhanced_aggregation_methods(prices: &[u128], stats: &EnhancedStatistic
     results
 }
 
-fn calculate_method_confidence(prices: &[u128], method: AggregationMethod) -> ConfidenceScore {
-    let base_confidence = match method {
-        AggregationMethod::Median => 85,
-        AggregationMethod::TrimmedMean => 80,
-        AggregationMethod::HodgesLehmann => 90,
-        AggregationMethod::WeightedConsensus => 75,
-        AggregationMethod::TimeWeightedAverage => 80,
-        AggregationMethod::VolatilityAdjusted => 70,
-        AggregationMethod::AdaptiveRobust => 95,
-    };
-    
-    // Adjust based on sample size
-    let size_bonus = match prices.len() {
-        1..=2 => 0,
-        3..=5 => 5,
-        6..=10 => 10,
-        _ => 15,
-    };
-    
-    let confidence = (base_confidence + size_bonus).min(99) as u8;
-    
+fn calculate_method_confidence(prices: &[u128], reveals: &[PriceReveal], method: AggregationMethod) -> ConfidenceScore {
+    let (bootstrap_variance, bayesian_interval, percentage) = bootstrap_confidence(prices, reveals, &method);
+
     ConfidenceScore {
-        percentage: confidence,
-        bayesian_interval: (0, 0),
-        bootstrap_variance: 0.0,
-        temporal_consistency: 0.0,
-        cross_validation_score: 0.0,
+        percentage,
+        bayesian_interval,
+        bootstrap_variance,
+        temporal_consistency: Fixed::ZERO,
+        cross_validation_score: Fixed::ZERO,
     }
 }
 
@@ -927,6 +1418,73 @@ fn validate_enhanced_consensus(prices: &[u128], consensus_price: u128) -> bool {
     threshold >= required_threshold
 }
 
+/// Incremental mean/variance/skewness/kurtosis estimator via Welford's
+/// recurrence, extended to third/fourth central moments (the combined
+/// Welford/West one-pass update). Deliberately kept in `f64` -- unlike the
+/// consensus-critical `EnhancedStatistics` fields, this is a best-effort
+/// estimator for in-method regime selection (`adaptive_robust`), computed
+/// fresh per node from the same reveal set, and lets the oracle fold
+/// reveals one at a time instead of holding the whole slice for a batch
+/// sum-of-squares (which is what overflowed before).
+#[derive(Debug, Clone, Copy, Default)]
+struct RunningStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+}
+
+impl RunningStats {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn update(&mut self, x: u128) {
+        let x = x as f64;
+        let n1 = self.count as f64;
+        self.count += 1;
+        let n = self.count as f64;
+        let delta = x - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * n1;
+
+        self.mean += delta_n;
+        self.m4 += term1 * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2 - 4.0 * delta_n * self.m3;
+        self.m3 += term1 * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term1;
+    }
+
+    fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    fn variance(&self) -> f64 {
+        if self.count < 2 { 0.0 } else { self.m2 / self.count as f64 }
+    }
+
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    fn skewness(&self) -> f64 {
+        let n = self.count as f64;
+        if self.count < 2 || self.m2 == 0.0 {
+            return 0.0;
+        }
+        (self.m3 / n) / (self.m2 / n).powf(1.5)
+    }
+
+    fn kurtosis(&self) -> f64 {
+        let n = self.count as f64;
+        if self.count < 2 || self.m2 == 0.0 {
+            return 0.0;
+        }
+        (self.m4 / n) / (self.m2 / n).powi(2) - 3.0
+    }
+}
+
 fn trimmed_mean(prices: &[u128], trim_percentage: f64) -> u128 {
     let trim_count = ((prices.len() as f64) * trim_percentage) as usize;
     let start = trim_count;
@@ -956,6 +1514,218 @@ fn hodges_lehmann_estimator(prices: &[u128]) -> u128 {
     median(&walsh_averages)
 }
 
+/// Gaussian kernel density mode: evaluates a KDE at every observed price
+/// (the reveals themselves are a sufficient grid at this sample size) and
+/// returns the price with the highest estimated density, plus a mode-gap
+/// score describing how dominant that mode is over any secondary cluster
+/// more than two bandwidths away. Everything is fixed-point (`Fixed::exp`
+/// for the Gaussian kernel, `Fixed::ln`/`Fixed::exp` for the fractional
+/// power in Silverman's rule) so the selected mode is bit-identical across
+/// nodes -- the normalizing `1/(bandwidth * sqrt(2*pi))` kernel constant is
+/// the same for every term and is dropped since only the argmax matters.
+fn kde_mode(prices: &[u128], stats: &EnhancedStatistics) -> (u128, Fixed) {
+    let n = prices.len();
+    if n < 3 {
+        return (median(prices), Fixed::ONE);
+    }
+
+    // Silverman's rule of thumb: h = 1.06 * robust_std_dev * n^(-1/5),
+    // with n^(-1/5) = exp(-ln(n) / 5).
+    let n_pow = Fixed::from_i64(n as i64).ln().mul(Fixed::from_ratio(-1, 5)).exp();
+    let silverman_factor = Fixed::from_ratio(106, 100).mul(n_pow);
+    let bandwidth = scale_u128_by_fixed(stats.robust_std_dev.max(1), silverman_factor).max(1);
+
+    let mut best_price = prices[0];
+    let mut best_density = Fixed::ZERO;
+    for &candidate in prices {
+        let mut density = Fixed::ZERO;
+        for &x_i in prices {
+            let diff: i128 = candidate as i128 - x_i as i128;
+            let u = Fixed::from_ratio(diff, bandwidth as i128);
+            let kernel = u.mul(u).mul(Fixed::from_ratio(-1, 2)).exp();
+            density = density.add(kernel);
+        }
+        if density.0 > best_density.0 {
+            best_density = density;
+            best_price = candidate;
+        }
+    }
+
+    // Secondary mode: the strongest density among candidates more than two
+    // bandwidths away from the primary mode.
+    let mut secondary_density = Fixed::ZERO;
+    for &candidate in prices {
+        let distance = if candidate > best_price { candidate - best_price } else { best_price - candidate };
+        if distance <= 2 * bandwidth {
+            continue;
+        }
+        let mut density = Fixed::ZERO;
+        for &x_i in prices {
+            let diff: i128 = candidate as i128 - x_i as i128;
+            let u = Fixed::from_ratio(diff, bandwidth as i128);
+            let kernel = u.mul(u).mul(Fixed::from_ratio(-1, 2)).exp();
+            density = density.add(kernel);
+        }
+        if density.0 > secondary_density.0 {
+            secondary_density = density;
+        }
+    }
+
+    let mode_gap = if best_density.is_zero() {
+        Fixed::ONE
+    } else {
+        Fixed::ONE.sub(Fixed::from_ratio(secondary_density.0 as i128, best_density.0 as i128)).max(Fixed::ZERO).min(Fixed::ONE)
+    };
+
+    (best_price, mode_gap)
+}
+
+/// A single t-digest cluster: a running mean of the points merged into it
+/// and their total weight (count).
+#[derive(Debug, Clone, Copy)]
+struct TDigestCentroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// Streaming quantile sketch (Dunning's t-digest). Centroids are kept
+/// sorted by mean; each incoming price merges into the nearest centroid
+/// that still has room under the scale function, or starts a new one.
+/// Bounding each centroid's weight by `4 * total_weight * compression * q
+/// * (1-q)` keeps centroids near the tails small (so extreme quantiles
+/// stay accurate) while letting centroids near the median grow large (so
+/// memory stays bounded even for very large reveal counts).
+#[derive(Debug, Clone)]
+struct TDigest {
+    centroids: Vec<TDigestCentroid>,
+    compression: f64,
+}
+
+impl TDigest {
+    fn new(compression: f64) -> Self {
+        TDigest { centroids: Vec::new(), compression }
+    }
+
+    fn from_prices(prices: &[u128]) -> Self {
+        let mut digest = TDigest::new(100.0);
+        for &price in prices {
+            digest.add(price);
+        }
+        digest
+    }
+
+    fn total_weight(&self) -> f64 {
+        self.centroids.iter().map(|c| c.weight).sum()
+    }
+
+    fn max_weight_at(&self, q: f64, total_weight: f64) -> f64 {
+        (4.0 * total_weight * q * (1.0 - q) / self.compression).max(1.0)
+    }
+
+    fn add(&mut self, value: u128) {
+        let x = value as f64;
+        let total = self.total_weight().max(1.0);
+
+        let mut best_index: Option<usize> = None;
+        let mut best_distance = f64::MAX;
+        let mut cumulative = 0.0;
+        for (i, centroid) in self.centroids.iter().enumerate() {
+            let q = (cumulative + centroid.weight / 2.0) / total;
+            let bound = self.max_weight_at(q, total);
+            let distance = (centroid.mean - x).abs();
+            if centroid.weight + 1.0 <= bound && distance < best_distance {
+                best_distance = distance;
+                best_index = Some(i);
+            }
+            cumulative += centroid.weight;
+        }
+
+        if let Some(i) = best_index {
+            let centroid = &mut self.centroids[i];
+            let new_weight = centroid.weight + 1.0;
+            centroid.mean += (x - centroid.mean) / new_weight;
+            centroid.weight = new_weight;
+        } else {
+            self.centroids.push(TDigestCentroid { mean: x, weight: 1.0 });
+            self.centroids.sort_by(|a, b| a.mean.partial_cmp(&b.mean).unwrap_or(std::cmp::Ordering::Equal));
+        }
+    }
+
+    /// Walks centroids accumulating weight and linearly interpolates
+    /// between adjacent centroids' means at the target cumulative weight.
+    fn estimate_quantile(&self, q: f64) -> u128 {
+        let n = self.centroids.len();
+        if n == 0 {
+            return 0;
+        }
+        if n == 1 {
+            return self.centroids[0].mean.max(0.0).round() as u128;
+        }
+
+        let total = self.total_weight();
+        let target = q.clamp(0.0, 1.0) * total;
+
+        let mut mids = Vec::with_capacity(n);
+        let mut cumulative = 0.0;
+        for c in &self.centroids {
+            mids.push(cumulative + c.weight / 2.0);
+            cumulative += c.weight;
+        }
+
+        if target <= mids[0] {
+            return self.centroids[0].mean.max(0.0).round() as u128;
+        }
+        if target >= mids[n - 1] {
+            return self.centroids[n - 1].mean.max(0.0).round() as u128;
+        }
+
+        for i in 0..n - 1 {
+            if target >= mids[i] && target <= mids[i + 1] {
+                let span = mids[i + 1] - mids[i];
+                let frac = if span > 0.0 { (target - mids[i]) / span } else { 0.0 };
+                let value = self.centroids[i].mean + frac * (self.centroids[i + 1].mean - self.centroids[i].mean);
+                return value.max(0.0).round() as u128;
+            }
+        }
+
+        self.centroids[n - 1].mean.max(0.0).round() as u128
+    }
+}
+
+/// Type-7 (Excel/R default) interpolated percentile over an already-sorted
+/// sample. `p` is a plain interpolation fraction (e.g. `0.25`, `0.5`,
+/// `0.75`) rather than a consensus-sensitive transcendental, so it is
+/// always called with the same compile-time literal on every node; the
+/// interpolation itself is done in fixed-point via `scale_u128_by_fixed`
+/// so the returned `u128` is bit-identical across executors regardless of
+/// how the host's `f64` multiply/floor happen to be scheduled.
+fn percentile(sorted: &[u128], p: f64) -> u128 {
+    let n = sorted.len();
+    if n == 0 {
+        return 0;
+    }
+    if n == 1 {
+        return sorted[0];
+    }
+
+    let p = p.clamp(0.0, 1.0);
+    let h = (n - 1) as f64 * p;
+    let lower = h.floor() as usize;
+    let lower = lower.min(n - 1);
+    let upper = (lower + 1).min(n - 1);
+
+    if lower == upper {
+        return sorted[lower];
+    }
+
+    let frac = h - lower as f64;
+    let frac_fixed = Fixed::from_ratio((frac * 1_000_000.0).round() as i128, 1_000_000);
+
+    let lo = sorted[lower];
+    let hi = sorted[upper];
+    lo + scale_u128_by_fixed(hi - lo, frac_fixed)
+}
+
 fn median(nums: &[u128]) -> u128 {
     let len = nums.len();
     if len == 0 {
@@ -971,6 +1741,38 @@ fn median(nums: &[u128]) -> u128 {
     }
 }
 
+/// Volume/liquidity-weighted median: sorts reveals by price and walks the
+/// cumulative liquidity weight, returning the price at which it first
+/// reaches half of the total weight. Deep-book reveals dominate the
+/// consensus over thin ones, unlike the unweighted `median` above.
+fn liquidity_weighted_median(reveals: &[PriceReveal]) -> u128 {
+    if reveals.is_empty() {
+        return 0;
+    }
+    if reveals.len() == 1 {
+        return reveals[0].price;
+    }
+
+    let mut by_price = reveals.to_vec();
+    by_price.sort_by_key(|r| r.price);
+
+    let total_liquidity: u128 = by_price.iter().map(|r| r.liquidity).sum();
+    if total_liquidity == 0 {
+        return median(&by_price.iter().map(|r| r.price).collect::<Vec<_>>());
+    }
+
+    let half_liquidity = total_liquidity / 2;
+    let mut cumulative: u128 = 0;
+    for reveal in &by_price {
+        cumulative += reveal.liquidity;
+        if cumulative >= half_liquidity {
+            return reveal.price;
+        }
+    }
+
+    by_price.last().unwrap().price
+}
+
 fn weighted_consensus(prices: &[u128]) -> u128 {
     // Enhanced weighted consensus with distance-based weighting
     let len = prices.len();
@@ -1011,30 +1813,150 @@ fn weighted_consensus(prices: &[u128]) -> u128 {
     }
 }
 
-fn time_weighted_average(prices: &[u128]) -> u128 {
-    // Simple time-weighted average - newer prices get higher weights
-    if prices.is_empty() {
+/// Half-life (seconds) for the exponential recency decay below. Smaller
+/// values forget older reveals faster.
+const TWA_HALF_LIFE_SECONDS: i64 = 300;
+
+/// Real exponential-decay time-weighted average: `w_i = exp(-lambda *
+/// age_i)` with `lambda = ln(2) / half_life`, combined multiplicatively
+/// with each reveal's `source_reliability`. `Fixed::exp` is already
+/// "protected" (clamped before evaluating), so every node derives the same
+/// weight for the same age regardless of `f64` transcendental support.
+fn time_weighted_average(reveals: &[PriceReveal]) -> u128 {
+    if reveals.is_empty() {
         return 0;
     }
-    
-    if prices.len() == 1 {
-        return prices[0];
+    if reveals.len() == 1 {
+        return reveals[0].price;
     }
-    
-    let mut weighted_sum = 0u128;
-    let mut total_weight = 0u128;
-    
-    for (i, &price) in prices.iter().enumerate() {
-        // Weight increases with recency (higher index = more recent)
-        let weight = (i + 1) as u128;
-        weighted_sum += price * weight;
-        total_weight += weight;
+
+    let newest_timestamp = reveals.iter().filter_map(|r| r.timestamp).max().unwrap_or(0);
+    let ln2 = Fixed::from_ratio(693147, 1_000_000);
+    let lambda = ln2.div(Fixed::from_i64(TWA_HALF_LIFE_SECONDS));
+
+    let mut weighted_sum: u128 = 0;
+    let mut total_weight = Fixed::ZERO;
+    for reveal in reveals {
+        let age = reveal.timestamp.map(|t| newest_timestamp.saturating_sub(t)).unwrap_or(0);
+        let exponent = Fixed::ZERO.sub(lambda.mul(Fixed::from_i64(age as i64)));
+        let decay = exponent.exp();
+        let reliability = Fixed::from_ratio((reveal.source_reliability * 1_000_000.0).round() as i128, 1_000_000);
+        let weight = decay.mul(reliability);
+
+        weighted_sum += scale_u128_by_fixed(reveal.price, weight);
+        total_weight = total_weight.add(weight);
     }
-    
-    if total_weight > 0 {
-        weighted_sum / total_weight
+
+    if total_weight.is_zero() {
+        return median(&reveals.iter().map(|r| r.price).collect::<Vec<_>>());
+    }
+
+    let recip_total_weight = Fixed::from_ratio(Fixed::ONE.0 as i128, total_weight.0 as i128);
+    scale_u128_by_fixed(weighted_sum, recip_total_weight)
+}
+
+/// Default lookback window (seconds) for [`time_weighted_average_windowed`].
+/// Reveals older than this relative to the newest reveal are dropped
+/// entirely rather than merely down-weighted, so a venue that stops
+/// publishing falls out of consensus instead of lingering at a vanishingly
+/// small but nonzero weight.
+const TWA_DEFAULT_WINDOW_SECONDS: u64 = 900;
+
+/// Sliding-window variant of [`time_weighted_average`]: reveals older than
+/// `window_seconds` (relative to the newest reveal) are dropped before the
+/// exponential-decay weighting runs, so the remaining weights renormalize
+/// over the surviving set instead of being diluted by stale venues.
+fn time_weighted_average_windowed(reveals: &[PriceReveal], window_seconds: u64) -> u128 {
+    if reveals.is_empty() {
+        return 0;
+    }
+
+    let newest_timestamp = reveals.iter().filter_map(|r| r.timestamp).max().unwrap_or(0);
+    let within_window: Vec<PriceReveal> = reveals
+        .iter()
+        .filter(|r| {
+            r.timestamp
+                .map(|t| newest_timestamp.saturating_sub(t) <= window_seconds)
+                .unwrap_or(true)
+        })
+        .cloned()
+        .collect();
+
+    if within_window.is_empty() {
+        return time_weighted_average(reveals);
+    }
+
+    time_weighted_average(&within_window)
+}
+
+// The i.i.d. bootstrap resampling used for the other aggregation methods
+// assumes independent draws, but TWA reveals arriving close together in
+// time are serially correlated, so a plain bootstrap interval understates
+// the true uncertainty here. Instead, re-sort by timestamp and estimate
+// the long-run variance of the weighted mean Newey-West style, weighting
+// lag-k autocovariance by a geometric bandwidth factor, then form the
+// interval with a Student's-t critical value.
+fn autocorrelation_corrected_interval(reveals: &[PriceReveal]) -> ((u128, u128), Fixed) {
+    let mut temporal = reveals.to_vec();
+    temporal.sort_by_key(|r| r.timestamp.unwrap_or(0));
+    let n = temporal.len();
+    if n < 2 {
+        let only = temporal.first().map(|r| r.price).unwrap_or(0);
+        return ((only, only), Fixed::ONE);
+    }
+
+    let series: Vec<f64> = temporal.iter().map(|r| r.price as f64).collect();
+    let mean: f64 = series.iter().sum::<f64>() / n as f64;
+    let demeaned: Vec<f64> = series.iter().map(|&x| x - mean).collect();
+
+    let gamma0 = demeaned.iter().map(|d| d * d).sum::<f64>() / n as f64;
+
+    const MAX_LAG: usize = 5;
+    const BANDWIDTH_COEFF: f64 = 0.5;
+    let mut long_run_variance = gamma0;
+    for k in 1..=MAX_LAG.min(n - 1) {
+        let gamma_k: f64 = (0..n - k).map(|i| demeaned[i] * demeaned[i + k]).sum::<f64>() / n as f64;
+        let weight = BANDWIDTH_COEFF.powi(k as i32);
+        long_run_variance += 2.0 * weight * gamma_k;
+    }
+    let long_run_variance = long_run_variance.max(0.0);
+
+    let variance_of_mean = long_run_variance / n as f64;
+    let std_error = variance_of_mean.sqrt();
+
+    let t_critical = student_t_critical_95(n - 1);
+
+    let half_width = (t_critical * std_error).max(0.0);
+    let lower = (mean - half_width).max(0.0).round() as u128;
+    let upper = (mean + half_width).round() as u128;
+
+    let temporal_consistency = if mean > 0.0 {
+        Fixed::from_ratio((((1.0 - (half_width / mean)).max(0.0)) * 1_000_000.0).round() as i128, 1_000_000)
     } else {
-        median(prices)
+        Fixed::ZERO
+    };
+
+    ((lower, upper), temporal_consistency)
+}
+
+// Small hardcoded two-tailed 95% Student's-t critical value table, since
+// this tree has no external stats crate to draw one from.
+fn student_t_critical_95(df: usize) -> f64 {
+    match df {
+        0 => 12.706,
+        1 => 12.706,
+        2 => 4.303,
+        3 => 3.182,
+        4 => 2.776,
+        5 => 2.571,
+        6 => 2.447,
+        7 => 2.365,
+        8 => 2.306,
+        9 => 2.262,
+        10..=15 => 2.145,
+        16..=20 => 2.093,
+        21..=30 => 2.042,
+        _ => 1.96,
     }
 }
 
@@ -1074,18 +1996,17 @@ fn adaptive_robust(prices: &[u128]) -> u128 {
         return median(prices);
     }
     
-    // Calculate coefficient of variation
-    let mean_price = prices.iter().sum::<u128>() / prices.len() as u128;
-    let variance: u128 = prices.iter()
-        .map(|&price| {
-            let diff = if price > mean_price { price - mean_price } else { mean_price - price };
-            (diff as u64 * diff as u64) as u128
-        })
-        .sum::<u128>() / prices.len() as u128;
-    
-    let std_dev = (variance as f64).sqrt() as u128;
-    let cv = if mean_price > 0 { (std_dev as f64 / mean_price as f64) * 100.0 } else { 0.0 };
-    
+    // Fold reveals through a streaming Welford estimator rather than a
+    // batch sum-of-squares, so a `count` that's too large to justify
+    // materializing twice (or prices near the top of the valid range)
+    // can't overflow a fixed-width squared-deviation accumulator.
+    let mut stats = RunningStats::new();
+    for &price in prices {
+        stats.update(price);
+    }
+    let mean_price = stats.mean() as u128;
+    let cv = if stats.mean() > 0.0 { (stats.std_dev() / stats.mean()) * 100.0 } else { 0.0 };
+
     // Choose method based on coefficient of variation
     if cv < 5.0 {
         // Low volatility: use mean
@@ -1098,3 +2019,123 @@ fn adaptive_robust(prices: &[u128]) -> u128 {
         median(prices)
     }
 }
+
+#[cfg(test)]
+mod tdigest_scale_regression {
+    use super::*;
+
+    // Regression check for the centroid scale-function bug: with
+    // `max_weight_at` dividing by `compression` (not multiplying), a
+    // simple non-degenerate sample should still split into multiple
+    // centroids, so q1/median/q3 stay distinct instead of collapsing to a
+    // single value.
+    #[test]
+    fn quantiles_stay_distinct_for_simple_sample() {
+        let stats = calculate_enhanced_statistics(&[1, 2, 3, 4, 5]);
+        assert!(stats.q1 < stats.median, "q1 ({}) should be below median ({})", stats.q1, stats.median);
+        assert!(stats.median < stats.q3, "median ({}) should be below q3 ({})", stats.median, stats.q3);
+    }
+}
+
+#[cfg(test)]
+mod fixed_arithmetic_tests {
+    use super::*;
+
+    // Q32.32 carries about 9-10 decimal digits of precision; `exp`/`ln` add
+    // Taylor-series/Newton-iteration error on top of that, so round-trips
+    // are checked to a looser tolerance than raw add/sub/mul/div.
+    fn assert_close(actual: Fixed, expected: f64, tolerance: f64) {
+        let actual = actual.to_f64();
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "expected ~{}, got {} (tolerance {})", expected, actual, tolerance
+        );
+    }
+
+    #[test]
+    fn from_i64_round_trips_through_to_f64() {
+        assert_close(Fixed::from_i64(7), 7.0, 1e-9);
+        assert_close(Fixed::from_i64(-3), -3.0, 1e-9);
+        assert_close(Fixed::ZERO, 0.0, 1e-9);
+        assert_close(Fixed::ONE, 1.0, 1e-9);
+    }
+
+    #[test]
+    fn from_ratio_matches_float_division() {
+        assert_close(Fixed::from_ratio(1, 4), 0.25, 1e-9);
+        assert_close(Fixed::from_ratio(-1, 4), -0.25, 1e-9);
+        assert_close(Fixed::from_ratio(10, 0), 0.0, 1e-9); // division by zero saturates to ZERO
+    }
+
+    #[test]
+    fn add_sub_mul_div_match_float_arithmetic() {
+        let a = Fixed::from_ratio(7, 2); // 3.5
+        let b = Fixed::from_ratio(3, 2); // 1.5
+
+        assert_close(a.add(b), 5.0, 1e-9);
+        assert_close(a.sub(b), 2.0, 1e-9);
+        assert_close(a.mul(b), 5.25, 1e-8);
+        assert_close(a.div(b), 3.5 / 1.5, 1e-8);
+    }
+
+    #[test]
+    fn div_by_zero_saturates_to_zero() {
+        let a = Fixed::from_i64(5);
+        assert_eq!(a.div(Fixed::ZERO), Fixed::ZERO);
+    }
+
+    #[test]
+    fn abs_min_max_behave_as_expected() {
+        let neg = Fixed::from_i64(-4);
+        let pos = Fixed::from_i64(4);
+        assert_close(neg.abs(), 4.0, 1e-9);
+        assert_eq!(neg.min(pos), neg);
+        assert_eq!(neg.max(pos), pos);
+    }
+
+    #[test]
+    fn cube_and_fourth_power_match_integer_powers() {
+        let x = Fixed::from_i64(3);
+        assert_close(x.cube(), 27.0, 1e-6);
+        assert_close(x.fourth_power(), 81.0, 1e-5);
+    }
+
+    #[test]
+    fn sqrt_is_exact_for_perfect_squares() {
+        assert_close(Fixed::from_i64(9).sqrt(), 3.0, 1e-9);
+        assert_close(Fixed::from_i64(16).sqrt(), 4.0, 1e-9);
+        assert_close(Fixed::from_i64(2).sqrt(), std::f64::consts::SQRT_2, 1e-6);
+    }
+
+    #[test]
+    fn sqrt_of_non_positive_is_zero() {
+        assert_eq!(Fixed::ZERO.sqrt(), Fixed::ZERO);
+        assert_eq!(Fixed::from_i64(-9).sqrt(), Fixed::ZERO);
+    }
+
+    #[test]
+    fn exp_matches_known_values() {
+        assert_close(Fixed::ZERO.exp(), 1.0, 1e-6);
+        assert_close(Fixed::ONE.exp(), std::f64::consts::E, 1e-4);
+    }
+
+    #[test]
+    fn ln_matches_known_values() {
+        assert_close(Fixed::ONE.ln(), 0.0, 1e-4);
+        assert_close(Fixed::from_i64(1).mul(Fixed::from_ratio(271828, 100000)).ln(), 1.0, 1e-3);
+    }
+
+    #[test]
+    fn ln_of_non_positive_is_zero() {
+        assert_eq!(Fixed::ZERO.ln(), Fixed::ZERO);
+        assert_eq!(Fixed::from_i64(-5).ln(), Fixed::ZERO);
+    }
+
+    #[test]
+    fn ln_and_exp_round_trip() {
+        for value in [Fixed::from_ratio(1, 2), Fixed::from_i64(2), Fixed::from_i64(5)] {
+            let round_tripped = value.ln().exp();
+            assert_close(round_tripped, value.to_f64(), 1e-3);
+        }
+    }
+}